@@ -0,0 +1,64 @@
+use crate::{Client, ClientError};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Inputs of the workspace's own year live where the day crates expect them,
+// <root>/day_<N>/input.txt, so anything fetched is picked up by the runner
+// straight away. Other years are kept apart in <root>/<year>/day_<N>.
+pub struct InputCache {
+    root: PathBuf,
+    year: u16,
+}
+
+impl InputCache {
+    pub fn new<P: AsRef<Path>>(root: P, year: u16) -> Self {
+        InputCache {
+            root: root.as_ref().to_path_buf(),
+            year,
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        let root = match year == self.year {
+            true => self.root.clone(),
+            false => self.root.join(year.to_string()),
+        };
+        root.join(format!("day_{}", day)).join("input.txt")
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<String> {
+        fs::read_to_string(self.path(year, day))
+            .ok()
+            .filter(|text| !text.is_empty())
+    }
+
+    // Returns the cached input, only downloading it when there is none. The
+    // flag says whether a download happened.
+    pub fn get_or_fetch(
+        &self,
+        client: &Client,
+        year: u16,
+        day: u8,
+    ) -> Result<(String, bool), ClientError> {
+        if let Some(text) = self.get(year, day) {
+            return Ok((text, false));
+        }
+
+        let text = client.fetch_input(year, day)?;
+        self.store(year, day, &text)?;
+        Ok((text, true))
+    }
+
+    // Writes through a temporary file so an interrupted write can never be
+    // mistaken for a cached input
+    fn store(&self, year: u16, day: u8, text: &str) -> Result<(), ClientError> {
+        let path = self.path(year, day);
+        let dir = path.parent().unwrap();
+        fs::create_dir_all(dir)?;
+
+        let partial = dir.join("input.txt.partial");
+        fs::write(&partial, text)?;
+        fs::rename(&partial, &path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{ClientError, Verdict};
+use std::time::Duration;
+use ureq::Agent;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// The site asks automated tools to identify themselves
+const USER_AGENT: &str = "github.com/jordansgrant/advent-of-code-2023 by aoc-client";
+
+// Talks to adventofcode.com, or any server laid out the same way
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent: Agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+        }
+    }
+
+    pub fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/input", self.day_url(year, day));
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &self.cookie())
+            .call()?;
+
+        let code = response.status().as_u16();
+        if code != 200 {
+            return Err(ClientError::Status { code, url });
+        }
+        Ok(response.body_mut().read_to_string()?)
+    }
+
+    // Posts an answer for one part, the level in the site's terms
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Verdict, ClientError> {
+        let url = format!("{}/answer", self.day_url(year, day));
+        let level = part.to_string();
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &self.cookie())
+            .send_form([("level", level.as_str()), ("answer", answer)])?;
+
+        let code = response.status().as_u16();
+        if code != 200 {
+            return Err(ClientError::Status { code, url });
+        }
+        Ok(Verdict::from_response(
+            &response.body_mut().read_to_string()?,
+        ))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
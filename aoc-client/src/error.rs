@@ -0,0 +1,47 @@
+use crate::Refusal;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum ClientError {
+    // The request never got a response
+    Http(ureq::Error),
+    // The server answered with something other than success
+    Status { code: u16, url: String },
+    Io(io::Error),
+    // The submission history rules the answer out without asking the server
+    Refused(Refusal),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::Http(e) => write!(f, "request failed: {}", e),
+            ClientError::Status { code: 404, url } => {
+                write!(f, "{} was not found, is the puzzle unlocked yet?", url)
+            }
+            ClientError::Status { code, url } if *code == 400 || *code == 500 => write!(
+                f,
+                "{} answered {}, the session token is probably invalid",
+                url, code
+            ),
+            ClientError::Status { code, url } => write!(f, "{} answered {}", url, code),
+            ClientError::Io(e) => write!(f, "{}", e),
+            ClientError::Refused(refusal) => write!(f, "not submitted, {}", refusal),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<ureq::Error> for ClientError {
+    fn from(e: ureq::Error) -> Self {
+        ClientError::Http(e)
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{Client, ClientError, Verdict};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const HEADER: &str = "# <unix time> <year> <day> <part> <verdict> <answer>";
+
+// The site makes you wait at least a minute after a wrong answer
+const WRONG_ANSWER_WAIT: Duration = Duration::from_secs(60);
+
+// Used when a rate limited response doesn't say how long to wait
+const DEFAULT_RATE_LIMIT_WAIT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    // Seconds since the unix epoch
+    pub time: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Attempt {
+    fn is_for(&self, year: u16, day: u8, part: u8) -> bool {
+        self.year == year && self.day == day && self.part == part
+    }
+
+    // When another answer may be sent after this one, if it holds that up
+    fn blocks_until(&self) -> Option<u64> {
+        let wait = match self.verdict {
+            Verdict::RateLimited(wait) => wait.unwrap_or(DEFAULT_RATE_LIMIT_WAIT),
+            verdict if verdict.is_wrong() => WRONG_ANSWER_WAIT,
+            _ => return None,
+        };
+        Some(self.time + wait.as_secs())
+    }
+}
+
+impl fmt::Display for Attempt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {}",
+            self.time, self.year, self.day, self.part, self.verdict, self.answer
+        )
+    }
+}
+
+// Why an answer was not sent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    // The same answer was sent before and judged wrong
+    KnownWrong(Verdict),
+    // At or above an answer known to be too high
+    AboveBound(String),
+    // At or below an answer known to be too low
+    BelowBound(String),
+    Wait(Duration),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "the part was already solved with {}", answer)
+            }
+            Refusal::KnownWrong(verdict) => {
+                write!(f, "this answer was already submitted and was {}", verdict)
+            }
+            Refusal::AboveBound(bound) => write!(f, "{} was already too high", bound),
+            Refusal::BelowBound(bound) => write!(f, "{} was already too low", bound),
+            Refusal::Wait(wait) => write!(f, "wait {}s before submitting again", wait.as_secs()),
+        }
+    }
+}
+
+// Every answer submitted so far, kept in a plain text file with one attempt
+// per line
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ClientError> {
+        let path = path.as_ref().to_path_buf();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let attempts = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(i, line)| {
+                parse_attempt(line).ok_or_else(|| {
+                    let message =
+                        format!("{}:{}: malformed attempt `{}`", path.display(), i + 1, line);
+                    ClientError::Io(io::Error::new(io::ErrorKind::InvalidData, message))
+                })
+            })
+            .collect::<Result<Vec<Attempt>, ClientError>>()?;
+
+        Ok(History { path, attempts })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    // Decides from past attempts alone whether sending the answer could be
+    // of any use, `now` being in seconds since the unix epoch
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), Refusal> {
+        let attempts = self.attempts.iter().filter(|a| a.is_for(year, day, part));
+        let value = answer.parse::<i64>().ok();
+
+        for attempt in attempts {
+            if attempt.verdict == Verdict::Correct {
+                return Err(Refusal::AlreadySolved(attempt.answer.clone()));
+            }
+            if attempt.verdict.is_wrong() && attempt.answer == answer {
+                return Err(Refusal::KnownWrong(attempt.verdict));
+            }
+
+            let bound = attempt.answer.parse::<i64>().ok();
+            let (Some(value), Some(bound)) = (value, bound) else {
+                continue;
+            };
+            if attempt.verdict == Verdict::TooHigh && value >= bound {
+                return Err(Refusal::AboveBound(attempt.answer.clone()));
+            }
+            if attempt.verdict == Verdict::TooLow && value <= bound {
+                return Err(Refusal::BelowBound(attempt.answer.clone()));
+            }
+        }
+
+        // The site's limits apply to the whole account, not just this part
+        let until = self.attempts.iter().filter_map(Attempt::blocks_until).max();
+        if let Some(until) = until.filter(|&until| until > now) {
+            return Err(Refusal::Wait(Duration::from_secs(until - now)));
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), ClientError> {
+        if let Some(dir) = self.path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let is_new = !self.path.exists();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        if is_new {
+            writeln!(file, "{}", HEADER)?;
+        }
+        writeln!(file, "{}", attempt)?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    // Sends the answer unless the history already rules it out, and records
+    // whatever the server made of it
+    pub fn submit(
+        &mut self,
+        client: &Client,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Verdict, ClientError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.check(year, day, part, answer, now)
+            .map_err(ClientError::Refused)?;
+
+        let verdict = client.submit_answer(year, day, part, answer)?;
+        self.record(Attempt {
+            time: now,
+            year,
+            day,
+            part,
+            answer: answer.to_owned(),
+            verdict,
+        })?;
+        Ok(verdict)
+    }
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let mut fields = line.splitn(6, ' ');
+    Some(Attempt {
+        time: fields.next()?.parse().ok()?,
+        year: fields.next()?.parse().ok()?,
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        verdict: fields.next()?.parse().ok()?,
+        answer: fields.next()?.to_owned(),
+    })
+}
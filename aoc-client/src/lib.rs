@@ -0,0 +1,11 @@
+mod cache;
+mod client;
+mod error;
+mod history;
+mod submit;
+
+pub use cache::InputCache;
+pub use client::{Client, DEFAULT_BASE_URL};
+pub use error::ClientError;
+pub use history::{Attempt, History, Refusal};
+pub use submit::Verdict;
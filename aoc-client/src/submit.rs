@@ -0,0 +1,103 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+// What the server made of a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    // Answered too soon after the last attempt, with the wait if given
+    RateLimited(Option<Duration>),
+    // The part was already solved, or isn't unlocked yet
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn from_response(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            return Verdict::Correct;
+        }
+        if body.contains("That's not the right answer") {
+            if body.contains("your answer is too high") {
+                return Verdict::TooHigh;
+            }
+            if body.contains("your answer is too low") {
+                return Verdict::TooLow;
+            }
+            return Verdict::Wrong;
+        }
+        if body.contains("You gave an answer too recently") {
+            return Verdict::RateLimited(parse_wait(body));
+        }
+        if body.contains("You don't seem to be solving the right level") {
+            return Verdict::WrongLevel;
+        }
+        Verdict::Unknown
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+// Reads the "You have 1m 5s left to wait" part of a rate limited response
+fn parse_wait(body: &str) -> Option<Duration> {
+    let start = body.find("You have ")? + "You have ".len();
+    let end = start + body[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for token in body[start..end].split_whitespace() {
+        let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let value = value.parse::<u64>().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::RateLimited(Some(wait)) => write!(f, "rate-limited:{}", wait.as_secs()),
+            Verdict::RateLimited(None) => write!(f, "rate-limited"),
+            Verdict::WrongLevel => write!(f, "wrong-level"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let verdict = match s {
+            "correct" => Verdict::Correct,
+            "wrong" => Verdict::Wrong,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "rate-limited" => Verdict::RateLimited(None),
+            "wrong-level" => Verdict::WrongLevel,
+            "unknown" => Verdict::Unknown,
+            _ => {
+                let seconds = s
+                    .strip_prefix("rate-limited:")
+                    .and_then(|s| s.parse::<u64>().ok())
+                    .ok_or_else(|| format!("unknown verdict `{}`", s))?;
+                Verdict::RateLimited(Some(Duration::from_secs(seconds)))
+            }
+        };
+        Ok(verdict)
+    }
+}
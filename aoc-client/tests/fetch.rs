@@ -0,0 +1,70 @@
+mod support;
+
+use aoc_client::{Client, ClientError, InputCache};
+use support::MockServer;
+
+#[test]
+fn fetches_input_with_the_session_cookie() {
+    let server = MockServer::start(vec![(200, "1 2 3\n")]);
+    let client = Client::new(&server.url, "abc123");
+
+    assert_eq!(client.fetch_input(2023, 9).unwrap(), "1 2 3\n");
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /2023/day/9/input HTTP/1.1\r\n"));
+    assert!(requests[0].contains("cookie: session=abc123\r\n"));
+}
+
+#[test]
+fn cached_inputs_are_never_fetched_again() {
+    let server = MockServer::start(vec![(200, "Time: 7\nDistance: 9\n")]);
+    let client = Client::new(&server.url, "abc123");
+    let dir = tempfile::tempdir().unwrap();
+    let cache = InputCache::new(dir.path(), 2023);
+
+    let (text, fetched) = cache.get_or_fetch(&client, 2023, 6).unwrap();
+    assert!(fetched);
+    assert_eq!(text, "Time: 7\nDistance: 9\n");
+    assert!(cache.path(2023, 6).ends_with("day_6/input.txt"));
+
+    let (text, fetched) = cache.get_or_fetch(&client, 2023, 6).unwrap();
+    assert!(!fetched);
+    assert_eq!(text, "Time: 7\nDistance: 9\n");
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn inputs_of_other_years_are_cached_apart() {
+    let server = MockServer::start(vec![(200, "2023 input\n"), (200, "2022 input\n")]);
+    let client = Client::new(&server.url, "abc123");
+    let dir = tempfile::tempdir().unwrap();
+    let cache = InputCache::new(dir.path(), 2023);
+
+    let (text, fetched) = cache.get_or_fetch(&client, 2023, 1).unwrap();
+    assert!(fetched);
+    assert_eq!(text, "2023 input\n");
+    let (text, fetched) = cache.get_or_fetch(&client, 2022, 1).unwrap();
+    assert!(fetched);
+    assert_eq!(text, "2022 input\n");
+
+    assert_eq!(cache.path(2023, 1), dir.path().join("day_1/input.txt"));
+    assert_eq!(cache.path(2022, 1), dir.path().join("2022/day_1/input.txt"));
+    assert_eq!(cache.get(2023, 1).unwrap(), "2023 input\n");
+    assert_eq!(cache.get(2022, 1).unwrap(), "2022 input\n");
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /2023/day/1/input HTTP/1.1\r\n"));
+    assert!(requests[1].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+}
+
+#[test]
+fn failed_fetches_leave_nothing_cached() {
+    let server = MockServer::start(vec![(404, "Not Found")]);
+    let client = Client::new(&server.url, "abc123");
+    let dir = tempfile::tempdir().unwrap();
+    let cache = InputCache::new(dir.path(), 2023);
+
+    let error = cache.get_or_fetch(&client, 2023, 25).unwrap_err();
+    assert!(matches!(error, ClientError::Status { code: 404, .. }));
+    assert!(!cache.path(2023, 25).exists());
+}
@@ -0,0 +1,119 @@
+mod support;
+
+use aoc_client::{Attempt, Client, ClientError, History, Refusal, Verdict};
+use std::time::Duration;
+use support::MockServer;
+
+const RIGHT: &str = "<article><p>That's the right answer! You are one gold star closer to restoring snow operations.</p></article>";
+const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again.</p></article>";
+const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2023/day/6\">[Return to Day 6]</a></p></article>";
+
+#[test]
+fn posts_the_answer_as_a_form() {
+    let server = MockServer::start(vec![(200, RIGHT)]);
+    let client = Client::new(&server.url, "abc123");
+
+    assert_eq!(
+        client.submit_answer(2023, 6, 2, "71503").unwrap(),
+        Verdict::Correct
+    );
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("POST /2023/day/6/answer HTTP/1.1\r\n"));
+    assert!(requests[0].contains("cookie: session=abc123\r\n"));
+    assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=71503"));
+}
+
+#[test]
+fn reads_every_kind_of_response() {
+    let cases = [
+        (RIGHT, Verdict::Correct),
+        (TOO_HIGH, Verdict::TooHigh),
+        (
+            "That's not the right answer; your answer is too low.",
+            Verdict::TooLow,
+        ),
+        (
+            "That's not the right answer.  If you're stuck...",
+            Verdict::Wrong,
+        ),
+        (
+            TOO_SOON,
+            Verdict::RateLimited(Some(Duration::from_secs(65))),
+        ),
+        (
+            "You don't seem to be solving the right level.  Did you already complete it?",
+            Verdict::WrongLevel,
+        ),
+        ("<html>Something else</html>", Verdict::Unknown),
+    ];
+    for (body, verdict) in cases {
+        assert_eq!(Verdict::from_response(body), verdict);
+        assert_eq!(verdict.to_string().parse::<Verdict>().unwrap(), verdict);
+    }
+}
+
+#[test]
+fn known_wrong_answers_are_never_sent_again() {
+    let server = MockServer::start(vec![(200, TOO_HIGH)]);
+    let client = Client::new(&server.url, "abc123");
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("submissions.txt");
+
+    let mut history = History::load(&path).unwrap();
+    assert_eq!(
+        history.submit(&client, 2023, 6, 1, "300").unwrap(),
+        Verdict::TooHigh
+    );
+
+    // Reloaded from disk, as a later run would
+    let mut history = History::load(&path).unwrap();
+    assert_eq!(history.attempts().len(), 1);
+    let error = history.submit(&client, 2023, 6, 1, "300").unwrap_err();
+    assert!(matches!(
+        error,
+        ClientError::Refused(Refusal::KnownWrong(Verdict::TooHigh))
+    ));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn refuses_answers_ruled_out_by_earlier_attempts() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut history = History::load(dir.path().join("submissions.txt")).unwrap();
+    let attempt = |time, part, answer: &str, verdict| Attempt {
+        time,
+        year: 2023,
+        day: 6,
+        part,
+        answer: answer.to_owned(),
+        verdict,
+    };
+    history
+        .record(attempt(1000, 1, "300", Verdict::TooHigh))
+        .unwrap();
+    history
+        .record(attempt(1100, 1, "200", Verdict::TooLow))
+        .unwrap();
+    history
+        .record(attempt(1200, 2, "71503", Verdict::Correct))
+        .unwrap();
+
+    assert_eq!(
+        history.check(2023, 6, 1, "301", 2000),
+        Err(Refusal::AboveBound("300".to_owned()))
+    );
+    assert_eq!(
+        history.check(2023, 6, 1, "150", 2000),
+        Err(Refusal::BelowBound("200".to_owned()))
+    );
+    assert_eq!(
+        history.check(2023, 6, 2, "1", 2000),
+        Err(Refusal::AlreadySolved("71503".to_owned()))
+    );
+    assert_eq!(
+        history.check(2023, 6, 1, "288", 1130),
+        Err(Refusal::Wait(Duration::from_secs(30)))
+    );
+    assert_eq!(history.check(2023, 6, 1, "288", 2000), Ok(()));
+}
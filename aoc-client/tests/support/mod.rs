@@ -0,0 +1,63 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// A stand-in HTTP server answering each connection with the next canned
+// response, and keeping every raw request it was sent
+pub struct MockServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+    pub fn start(responses: Vec<(u16, &str)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = Arc::clone(&requests);
+        let responses: Vec<(u16, String)> = responses
+            .into_iter()
+            .map(|(c, b)| (c, b.to_owned()))
+            .collect();
+
+        thread::spawn(move || {
+            for (code, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut body_bytes = vec![0; content_length];
+                reader.read_exact(&mut body_bytes).unwrap();
+                request.push_str(&String::from_utf8_lossy(&body_bytes));
+                recorded.lock().unwrap().push(request);
+
+                let response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    code,
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        MockServer { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
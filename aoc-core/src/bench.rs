@@ -0,0 +1,142 @@
+use crate::input::{self, Source};
+use crate::runner::{Day, Part};
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Timing {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        Timing {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub day: u8,
+    pub iterations: usize,
+    pub input_bytes: usize,
+    pub stages: Vec<(Stage, Timing)>,
+}
+
+impl Bench {
+    // Bytes of input handled per second, going by the median run
+    pub fn throughput(&self, timing: &Timing) -> f64 {
+        let seconds = timing.median.as_secs_f64();
+        if seconds == 0.0 {
+            return f64::INFINITY;
+        }
+        self.input_bytes as f64 / seconds
+    }
+}
+
+// Reads the input once, then parses and solves it `iterations` times. Parts
+// that read different sample files have their parse times added together.
+pub fn run(day: &Day, source: &Source, parts: &[Part], iterations: usize) -> Result<Bench, String> {
+    let loaded = input::load(source, Path::new(day.dir), parts)?;
+    let input_bytes = loaded.iter().map(|l| l.text.len()).sum();
+
+    let mut parse_samples: Vec<Duration> = vec![];
+    let mut part_samples: Vec<(Part, Vec<Duration>)> = parts.iter().map(|&p| (p, vec![])).collect();
+
+    for _ in 0..iterations.max(1) {
+        let mut parse_elapsed = Duration::ZERO;
+        for l in loaded.iter() {
+            let report = day
+                .execute(&l.text, &l.parts)
+                .map_err(|e| e.diagnostic(&l.text, &l.origin))?;
+            parse_elapsed += report.parse_elapsed;
+
+            for outcome in report.outcomes {
+                if let Some((_, samples)) =
+                    part_samples.iter_mut().find(|(p, _)| *p == outcome.part)
+                {
+                    samples.push(outcome.elapsed);
+                }
+            }
+        }
+        parse_samples.push(parse_elapsed);
+    }
+
+    let mut stages = vec![(Stage::Parse, Timing::from_samples(&parse_samples))];
+    for (part, samples) in part_samples {
+        stages.push((Stage::Solve(part), Timing::from_samples(&samples)));
+    }
+
+    Ok(Bench {
+        day: day.number,
+        iterations: iterations.max(1),
+        input_bytes,
+        stages,
+    })
+}
+
+pub const CSV_HEADER: &str =
+    "day,stage,iterations,input_bytes,min_ns,median_ns,max_ns,throughput_bytes_per_s";
+
+pub fn to_csv(benches: &[Bench]) -> String {
+    let mut lines = vec![CSV_HEADER.to_owned()];
+    for bench in benches {
+        for (stage, timing) in bench.stages.iter() {
+            lines.push(format!(
+                "{},{},{},{},{},{},{},{:.0}",
+                bench.day,
+                stage,
+                bench.iterations,
+                bench.input_bytes,
+                timing.min.as_nanos(),
+                timing.median.as_nanos(),
+                timing.max.as_nanos(),
+                bench.throughput(timing)
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+// Scales a bytes per second figure to a readable unit
+pub fn format_throughput(bytes_per_second: f64) -> String {
+    let units = ["B/s", "KB/s", "MB/s", "GB/s"];
+    let mut value = bytes_per_second;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < units.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, units[unit])
+}
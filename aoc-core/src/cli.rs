@@ -0,0 +1,65 @@
+use crate::input::Source;
+use crate::runner::{Day, Part};
+use crate::Solution;
+use clap::{Args, Parser};
+use std::process::ExitCode;
+
+#[derive(Args, Debug, Clone)]
+pub struct InputArgs {
+    /// Puzzle input file, or - to read stdin [default: the day's input.txt]
+    #[arg(short, long)]
+    pub input: Option<String>,
+
+    /// Solve the day's sample input instead, optionally picking the Nth sample
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "1", conflicts_with = "input")]
+    pub sample: Option<u8>,
+}
+
+impl InputArgs {
+    pub fn source(&self) -> Source {
+        if let Some(n) = self.sample {
+            return Source::Sample(n);
+        }
+        match &self.input {
+            Some(arg) => Source::from_arg(arg),
+            None => Source::Default,
+        }
+    }
+}
+
+#[derive(Parser)]
+struct DayArgs {
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+// Entry point shared by the day binaries, printing one answer per line
+pub fn main<S: Solution>() -> ExitCode {
+    run::<S>(&DayArgs::parse().input)
+}
+
+// For day binaries with flags of their own, which parse the input flags
+// themselves
+pub fn run<S: Solution>(input: &InputArgs) -> ExitCode {
+    let day = Day::new::<S>();
+
+    match day.solve(&input.source(), Part::BOTH) {
+        Ok(report) => {
+            let mut code = ExitCode::SUCCESS;
+            for outcome in report.outcomes.iter() {
+                match &outcome.answer {
+                    Ok(answer) => println!("{}", answer),
+                    Err(e) => {
+                        eprintln!("part {}: {}", outcome.part, e);
+                        code = ExitCode::FAILURE;
+                    }
+                }
+            }
+            code
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
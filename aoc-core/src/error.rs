@@ -0,0 +1,115 @@
+use std::error::Error;
+use std::fmt;
+
+// Where and why an input failed to parse. `line` and `column` are 1-based and
+// `found` is the offending text, empty when the input ended too early.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub found: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    // `found` must be a slice of `source`, which is how its position is known
+    pub fn new(source: &str, found: &str, expected: &str) -> Self {
+        let (line, column) = position(source, found);
+
+        ParseError {
+            line,
+            column,
+            found: found.to_owned(),
+            expected: expected.to_owned(),
+        }
+    }
+
+    // For input that stops before an expected token
+    pub fn at_end(source: &str, expected: &str) -> Self {
+        ParseError::new(source, &source[source.len()..], expected)
+    }
+
+    // Re-bases an error raised while parsing `fragment` onto `source`, the
+    // text `fragment` was sliced from
+    pub fn in_context(mut self, source: &str, fragment: &str) -> Self {
+        let (line, column) = position(source, fragment);
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+
+    // Renders the error rustc-style, pointing a caret at the offending text
+    pub fn diagnostic(&self, source: &str, origin: &str) -> String {
+        self.render("error", source, origin)
+    }
+
+    // The same rendering for problems that didn't stop the parse
+    pub fn warning(&self, source: &str, origin: &str) -> String {
+        self.render("warning", source, origin)
+    }
+
+    fn render(&self, level: &str, source: &str, origin: &str) -> String {
+        let text = source.split('\n').nth(self.line - 1).unwrap_or("");
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let carets = "^".repeat(self.found.chars().count().max(1));
+
+        format!(
+            "{}: expected {}, found {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}",
+            level,
+            self.expected,
+            self.describe_found(),
+            gutter,
+            origin,
+            self.line,
+            self.column,
+            gutter,
+            number,
+            text.trim_end_matches('\r'),
+            gutter,
+            " ".repeat(self.column - 1),
+            carets
+        )
+    }
+
+    fn describe_found(&self) -> String {
+        if self.found.is_empty() {
+            return "end of line".to_owned();
+        }
+        format!("`{}`", self.found)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line,
+            self.column,
+            self.expected,
+            self.describe_found()
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+// 1-based line and column of `fragment` within `source`, falling back to the
+// start when it isn't actually a slice of it
+fn position(source: &str, fragment: &str) -> (usize, usize) {
+    let start = source.as_ptr() as usize;
+    let offset = (fragment.as_ptr() as usize).wrapping_sub(start);
+    if offset > source.len() || !source.is_char_boundary(offset) {
+        return (1, 1);
+    }
+
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = before[line_start..].chars().count() + 1;
+
+    (line, column)
+}
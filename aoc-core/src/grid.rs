@@ -0,0 +1,199 @@
+use crate::ParseError;
+use std::ops::{Index, IndexMut, Range};
+
+// Offsets to the cells sharing a side with a cell, clockwise from above
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+// Offsets to the cells sharing a side or a corner with a cell
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+// A rectangle of cells addressed by `(x, y)`, the column and the row counted
+// from the top left
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    // Row after row
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn build(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    // One row per non-empty line and one cell per character, all rows being
+    // as wide as the first. `cell` converts a character, `expected` saying
+    // what it accepts when it gives None.
+    pub fn parse_with<F>(input: &str, expected: &str, cell: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut cells: Vec<T> = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for line in crate::lines(input) {
+            let row_width = line.chars().count();
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                let expected = format!("a row {} characters wide", width);
+                return Err(ParseError::new(input, line, &expected));
+            }
+
+            for (i, c) in line.char_indices() {
+                let Some(value) = cell(c) else {
+                    let found = &line[i..i + c.len_utf8()];
+                    return Err(ParseError::new(input, found, expected));
+                };
+                cells.push(value);
+            }
+            height += 1;
+        }
+
+        let Some(width) = width else {
+            return Err(ParseError::at_end(input, "a grid"));
+        };
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        if !self.contains(position) {
+            return None;
+        }
+        Some(&self.cells[position.1 * self.width + position.0])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if !self.contains(position) {
+            return None;
+        }
+        Some(&mut self.cells[position.1 * self.width + position.0])
+    }
+
+    // The position `offset` away, if it is still within the grid
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let moved = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(moved).then_some(moved)
+    }
+
+    // The positions at each of the offsets that are within the grid
+    pub fn neighbors<'a>(
+        &'a self,
+        position: (usize, usize),
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    pub fn neighbors_4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(position, &ORTHOGONAL)
+    }
+
+    pub fn neighbors_8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(position, &SURROUNDING)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} of a grid {} wide", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    // Every cell with its position, row after row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i % self.width, i / self.width), cell))
+    }
+
+    // The cells of the rectangle spanning the columns and rows given, cut
+    // down to the grid, row after row
+    pub fn region(
+        &self,
+        xs: Range<usize>,
+        ys: Range<usize>,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let xs = xs.start.min(self.width)..xs.end.min(self.width);
+        let ys = ys.start.min(self.height)..ys.end.min(self.height);
+        ys.flat_map(move |y| {
+            let row = self.row(y);
+            xs.clone().map(move |x| ((x, y), &row[x]))
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        match self.get(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside a {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(position) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside a {}x{} grid", position, width, height),
+        }
+    }
+}
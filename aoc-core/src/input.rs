@@ -0,0 +1,100 @@
+use crate::runner::Part;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    // The day's own input.txt
+    Default,
+    File(PathBuf),
+    Stdin,
+    // The Nth sample shipped with the day, counting from 1
+    Sample(u8),
+}
+
+impl Source {
+    // "-" is the conventional name for stdin
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            return Source::Stdin;
+        }
+        Source::File(PathBuf::from(arg))
+    }
+
+    // The file backing this source for a part, None when reading stdin
+    pub fn path(&self, dir: &Path, part: Part) -> Result<Option<PathBuf>, String> {
+        match self {
+            Source::Default => Ok(Some(dir.join("input.txt"))),
+            Source::File(path) => Ok(Some(path.clone())),
+            Source::Stdin => Ok(None),
+            Source::Sample(n) => sample_names(*n, part)
+                .into_iter()
+                .map(|name| dir.join(name))
+                .find(|path| path.is_file())
+                .map(Some)
+                .ok_or_else(|| format!("no sample input {} in {}", n, dir.display())),
+        }
+    }
+}
+
+// Samples are named sample_input.txt, sample_input_2.txt, ... and a part may
+// override the first with its own part_2_sample_input.txt
+fn sample_names(n: u8, part: Part) -> Vec<String> {
+    let mut names = vec![];
+    if n == 1 {
+        names.push(format!("part_{}_sample_input.txt", part));
+        names.push("sample_input.txt".to_owned());
+    } else {
+        names.push(format!("part_{}_sample_input_{}.txt", part, n));
+        names.push(format!("sample_input_{}.txt", n));
+    }
+    names
+}
+
+// Text to solve a set of parts with, along with where it came from
+#[derive(Debug, Clone)]
+pub struct Loaded {
+    pub origin: String,
+    pub parts: Vec<Part>,
+    pub text: String,
+}
+
+// Reads the input for each requested part, grouping parts that share a file
+// so that it is only parsed once
+pub fn load(source: &Source, dir: &Path, parts: &[Part]) -> Result<Vec<Loaded>, String> {
+    let mut loaded: Vec<Loaded> = vec![];
+
+    for &part in parts {
+        let path = source.path(dir, part)?;
+        let origin = match &path {
+            Some(path) => path.display().to_string(),
+            None => "stdin".to_owned(),
+        };
+
+        if let Some(existing) = loaded.iter_mut().find(|l| l.origin == origin) {
+            existing.parts.push(part);
+            continue;
+        }
+
+        let text = match &path {
+            Some(path) => fs::read_to_string(path),
+            None => read_stdin(),
+        }
+        .map_err(|e| format!("failed to read {}: {}", origin, e))?;
+
+        loaded.push(Loaded {
+            origin,
+            parts: vec![part],
+            text,
+        });
+    }
+
+    Ok(loaded)
+}
+
+fn read_stdin() -> io::Result<String> {
+    let mut text = String::new();
+    io::stdin().read_to_string(&mut text)?;
+    Ok(text)
+}
@@ -0,0 +1,30 @@
+use std::str::FromStr;
+
+pub mod bench;
+pub mod cli;
+mod error;
+pub mod grid;
+pub mod input;
+pub mod runner;
+mod solution;
+pub mod table;
+pub mod testing;
+
+pub use error::ParseError;
+pub use grid::Grid;
+pub use solution::{Answer, Solution};
+
+// Non-empty lines of the input, tolerating a trailing newline
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.split('\n').filter(|&s| !s.is_empty())
+}
+
+// Parses every non-empty line, reporting errors at their place in the input
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    lines(input)
+        .map(|line| line.parse::<T>().map_err(|e| e.in_context(input, line)))
+        .collect()
+}
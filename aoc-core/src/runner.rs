@@ -0,0 +1,168 @@
+use crate::input::{self, Source};
+use crate::{Answer, ParseError, Solution};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl Part {
+    pub const BOTH: &'static [Part] = &[Part::One, Part::Two];
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("there is no part {}", value)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", *self as u8)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub part: Part,
+    // Why the part has no answer when it failed
+    pub answer: Result<String, String>,
+    // Type of the answer before it was formatted, such as `u64`
+    pub answer_type: &'static str,
+    pub elapsed: Duration,
+}
+
+// An input that was parsed while solving, with the parts it was used for
+#[derive(Debug, Clone)]
+pub struct InputInfo {
+    pub origin: String,
+    pub sha256: String,
+    pub parts: Vec<Part>,
+    pub parse_elapsed: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub parse_elapsed: Duration,
+    pub outcomes: Vec<Outcome>,
+    // Only filled in when the report comes from Day::solve
+    pub inputs: Vec<InputInfo>,
+}
+
+impl Report {
+    pub fn total_elapsed(&self) -> Duration {
+        self.outcomes
+            .iter()
+            .fold(self.parse_elapsed, |acc, o| acc + o.elapsed)
+    }
+
+    // The part's answer, or why it has none, if it was solved
+    pub fn answer(&self, part: Part) -> Option<Result<&str, &str>> {
+        self.outcomes
+            .iter()
+            .find(|o| o.part == part)
+            .map(|o| o.answer.as_deref().map_err(|e| e.as_str()))
+    }
+
+    pub fn input_for(&self, part: Part) -> Option<&InputInfo> {
+        self.inputs.iter().find(|i| i.parts.contains(&part))
+    }
+}
+
+// Parses once and then solves each requested part, timing every stage
+pub fn execute<S: Solution>(input: &str, parts: &[Part]) -> Result<Report, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_elapsed = start.elapsed();
+
+    let outcomes = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (answer, answer_type) = match part {
+                Part::One => (S::part_1(&parsed).into_answer(), S::Answer1::TYPE),
+                Part::Two => (S::part_2(&parsed).into_answer(), S::Answer2::TYPE),
+            };
+
+            Outcome {
+                part,
+                answer,
+                answer_type,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Report {
+        parse_elapsed,
+        outcomes,
+        inputs: vec![],
+    })
+}
+
+// A type-erased handle on a day's Solution, so days can be listed together
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub dir: &'static str,
+    execute: fn(&str, &[Part]) -> Result<Report, ParseError>,
+}
+
+impl Day {
+    pub fn new<S: Solution>() -> Self {
+        Day {
+            number: S::DAY,
+            dir: S::DIR,
+            execute: execute::<S>,
+        }
+    }
+
+    pub fn execute(&self, input: &str, parts: &[Part]) -> Result<Report, ParseError> {
+        (self.execute)(input, parts)
+    }
+
+    // Loads the parts' input from the source and solves them, parts reading
+    // different files (as samples can) are parsed separately
+    pub fn solve(&self, source: &Source, parts: &[Part]) -> Result<Report, String> {
+        let mut report = Report {
+            parse_elapsed: Duration::ZERO,
+            outcomes: vec![],
+            inputs: vec![],
+        };
+
+        for loaded in input::load(source, Path::new(self.dir), parts)? {
+            let partial = self
+                .execute(&loaded.text, &loaded.parts)
+                .map_err(|e| e.diagnostic(&loaded.text, &loaded.origin))?;
+            report.parse_elapsed += partial.parse_elapsed;
+            report.outcomes.extend(partial.outcomes);
+            report.inputs.push(InputInfo {
+                sha256: sha256_hex(&loaded.text),
+                origin: loaded.origin,
+                parts: loaded.parts,
+                parse_elapsed: partial.parse_elapsed,
+            });
+        }
+        report.outcomes.sort_by_key(|o| o.part);
+
+        Ok(report)
+    }
+}
+
+pub fn sha256_hex(text: &str) -> String {
+    Sha256::digest(text.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
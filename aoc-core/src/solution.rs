@@ -0,0 +1,50 @@
+use crate::ParseError;
+
+// What a part gives back: an answer to print, or why there is none
+pub trait Answer {
+    // Name of the answer's type, the same for a part that can fail
+    const TYPE: &'static str;
+
+    fn into_answer(self) -> Result<String, String>;
+}
+
+macro_rules! display_answers {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            const TYPE: &'static str = stringify!($t);
+
+            fn into_answer(self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+display_answers!(u32, u64, usize, i32, i64, String);
+
+// For parts that can't solve every input that parses
+impl<T: Answer> Answer for Result<T, String> {
+    const TYPE: &'static str = T::TYPE;
+
+    fn into_answer(self) -> Result<String, String> {
+        self.and_then(Answer::into_answer)
+    }
+}
+
+// A day's puzzle split into its stages so that callers can drive (and time)
+// parsing separately from either part
+pub trait Solution {
+    const DAY: u8;
+    // Directory holding the day's input.txt and sample inputs
+    const DIR: &'static str;
+
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_1(input: &Self::Input) -> Self::Answer1;
+
+    fn part_2(input: &Self::Input) -> Self::Answer2;
+}
@@ -0,0 +1,37 @@
+// Renders rows as a plain text table, sizing each column to its widest cell
+pub fn render(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = headers
+        .iter()
+        .enumerate()
+        .map(|(i, h)| {
+            rows.iter()
+                .filter_map(|r| r.get(i))
+                .fold(h.len(), |acc, cell| acc.max(cell.len()))
+        })
+        .collect();
+
+    let format_row = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join(" | ")
+            .trim_end()
+            .to_owned()
+    };
+
+    let mut lines = vec![format_row(headers.to_vec())];
+    lines.push(
+        widths
+            .iter()
+            .map(|&w| "-".repeat(w))
+            .collect::<Vec<String>>()
+            .join("-+-"),
+    );
+    for row in rows {
+        lines.push(format_row(row.iter().map(|s| s.as_str()).collect()));
+    }
+
+    lines.join("\n")
+}
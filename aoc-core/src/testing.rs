@@ -0,0 +1,79 @@
+use crate::input::Source;
+use crate::runner::{Day, Part};
+use crate::Solution;
+use std::fs;
+use std::path::Path;
+
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+// A recorded answer: solving `part` of the day's `file` must give `answer`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub file: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+// One `<file> <part> <answer>` entry per line, # starts a comment
+pub fn parse_answers(text: &str) -> Result<Vec<Expected>, String> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.split('#').next().unwrap().trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(number, line)| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [file, part, answer] = fields[..] else {
+                return Err(format!(
+                    "line {}: expected `<file> <part> <answer>`",
+                    number
+                ));
+            };
+            let part = part
+                .parse::<u8>()
+                .map_err(|e| e.to_string())
+                .and_then(Part::try_from)
+                .map_err(|e| format!("line {}: {}", number, e))?;
+
+            Ok(Expected {
+                file: file.to_owned(),
+                part,
+                answer: answer.to_owned(),
+            })
+        })
+        .collect()
+}
+
+// Solves every entry of the day's answers.txt, panicking with the full list of
+// mismatches so that one regression doesn't hide another
+pub fn check_answers<S: Solution>() {
+    let day = Day::new::<S>();
+    let path = Path::new(day.dir).join(ANSWERS_FILE);
+    let text = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    let expected = parse_answers(&text).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    assert!(!expected.is_empty(), "{} has no answers", path.display());
+
+    let failures: Vec<String> = expected
+        .iter()
+        .filter_map(|e| {
+            let source = Source::File(Path::new(day.dir).join(&e.file));
+            let actual = match day.solve(&source, &[e.part]) {
+                Ok(report) => match report.answer(e.part) {
+                    Some(Ok(answer)) => answer.to_owned(),
+                    Some(Err(err)) => format!("no answer ({})", err),
+                    None => String::new(),
+                },
+                Err(err) => err,
+            };
+            if actual == e.answer {
+                return None;
+            }
+            Some(format!(
+                "day {} part {} of {}: expected {}, got {}",
+                day.number, e.part, e.file, e.answer, actual
+            ))
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
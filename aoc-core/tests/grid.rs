@@ -0,0 +1,58 @@
+use aoc_core::Grid;
+
+fn digits(input: &str) -> Grid<u32> {
+    Grid::parse_with(input, "a digit", |c| c.to_digit(10)).unwrap()
+}
+
+#[test]
+fn parses_one_cell_per_character() {
+    let grid = digits("123\n456\n");
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 1)], 6);
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.row(1), [4, 5, 6]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<u32>>(), [2, 5]);
+
+    let error = Grid::parse_with("12\n3x\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.found.as_str()),
+        (2, 2, "x")
+    );
+
+    let error = Grid::parse_with("12\n345\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+    assert_eq!(
+        (error.line, error.expected.as_str()),
+        (2, "a row 2 characters wide")
+    );
+
+    let error = Grid::parse_with("\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+    assert_eq!(error.expected, "a grid");
+}
+
+#[test]
+fn neighborhoods_stop_at_the_edges() {
+    let grid = digits("123\n456\n789\n");
+    let around = |position| -> Vec<u32> { grid.neighbors_8(position).map(|p| grid[p]).collect() };
+
+    assert_eq!(around((1, 1)), [1, 2, 3, 6, 9, 8, 7, 4]);
+    assert_eq!(around((0, 0)), [2, 5, 4]);
+    assert_eq!(
+        grid.neighbors_4((2, 2))
+            .map(|p| grid[p])
+            .collect::<Vec<u32>>(),
+        [6, 8]
+    );
+    assert_eq!(grid.offset((0, 2), (-1, 0)), None);
+    assert_eq!(grid.offset((0, 2), (2, -2)), Some((2, 0)));
+}
+
+#[test]
+fn regions_are_cut_down_to_the_grid() {
+    let mut grid = digits("123\n456\n789\n");
+    grid[(1, 1)] = 0;
+
+    let cells: Vec<u32> = grid.region(1..5, 1..3).map(|(_, &c)| c).collect();
+    assert_eq!(cells, [0, 6, 8, 9]);
+    assert_eq!(grid.region(2..3, 5..9).count(), 0);
+    assert_eq!(grid.iter().nth(4), Some(((1, 1), &0)));
+}
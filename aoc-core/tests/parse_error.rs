@@ -0,0 +1,35 @@
+use aoc_core::ParseError;
+
+#[test]
+fn locates_found_text_within_source() {
+    let input = "Game 1: 3 blue\nGame 2: x red\n";
+    let found = &input[23..24];
+    let error = ParseError::new(input, found, "a cube count");
+
+    assert_eq!((error.line, error.column), (2, 9));
+    assert_eq!(error.found, "x");
+}
+
+#[test]
+fn rebases_line_errors_onto_the_whole_input() {
+    let input = "Card 1: 1 | 2\nCard 2: 3 | y\n";
+    let line = aoc_core::lines(input).nth(1).unwrap();
+    let error = ParseError::new(line, &line[12..], "a card number").in_context(input, line);
+
+    assert_eq!((error.line, error.column), (2, 13));
+}
+
+#[test]
+fn renders_a_caret_under_the_offending_text() {
+    let input = "32T3K 765\nQQQJX 483\n";
+    let error = ParseError::new(input, &input[14..15], "a card label");
+
+    assert_eq!(
+        error.diagnostic(input, "input.txt"),
+        "error: expected a card label, found `X`\n \
+         --> input.txt:2:5\n  \
+         |\n\
+         2 | QQQJX 483\n  \
+         |     ^"
+    );
+}
@@ -0,0 +1,19 @@
+use aoc_core::runner::Day;
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day_1::Day1>(),
+        Day::new::<day_2::Day2>(),
+        Day::new::<day_3::Day3>(),
+        Day::new::<day_4::Day4>(),
+        Day::new::<day_5::Day5>(),
+        Day::new::<day_6::Day6>(),
+        Day::new::<day_7::Day7>(),
+        Day::new::<day_8::Day8>(),
+        Day::new::<day_9::Day9>(),
+    ]
+}
+
+pub fn find(number: u8) -> Option<Day> {
+    all().into_iter().find(|d| d.number == number)
+}
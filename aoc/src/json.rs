@@ -0,0 +1,20 @@
+use aoc_core::runner::{Day, Outcome, Report};
+use serde_json::{json, Value};
+
+// One self-contained record per solved part, so output can be streamed as
+// JSON Lines. A part that failed has a null `answer` and an `error` saying why.
+pub fn outcome(day: &Day, report: &Report, outcome: &Outcome) -> Value {
+    let input = report.input_for(outcome.part);
+
+    json!({
+        "day": day.number,
+        "part": outcome.part as u8,
+        "answer": outcome.answer.as_ref().ok(),
+        "error": outcome.answer.as_ref().err(),
+        "answer_type": outcome.answer_type,
+        "input": input.map(|i| i.origin.as_str()),
+        "input_sha256": input.map(|i| i.sha256.as_str()),
+        "parse_ns": input.map(|i| i.parse_elapsed.as_nanos() as u64),
+        "elapsed_ns": outcome.elapsed.as_nanos() as u64,
+    })
+}
@@ -0,0 +1,271 @@
+use aoc_core::bench::{self, Bench};
+use aoc_core::cli::InputArgs;
+use aoc_core::input::Source;
+use aoc_core::runner::{Day, Part, Report};
+use aoc_core::table;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::process::ExitCode;
+
+mod days;
+mod json;
+mod remote;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every implemented day with --all
+    Run(RunArgs),
+    /// Time each stage of a day, or of every day with --all, over many runs
+    Bench(BenchArgs),
+    /// Download a day's puzzle input, unless it is already on disk
+    Fetch(remote::FetchArgs),
+    /// Send an answer to the puzzle server, keeping a history of attempts
+    Submit(remote::SubmitArgs),
+}
+
+// Which days, parts and input a command works on
+#[derive(Args)]
+struct Selection {
+    /// Day to solve
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Only solve this part (1 or 2), both are solved otherwise
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Solve every implemented day in sequence
+    #[arg(long, conflicts_with = "input")]
+    all: bool,
+}
+
+impl Selection {
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![Part::try_from(part).unwrap()],
+            None => Part::BOTH.to_vec(),
+        }
+    }
+
+    fn days(&self) -> Result<Vec<Day>, String> {
+        if self.all {
+            return Ok(days::all());
+        }
+
+        let number = self.day.unwrap();
+        match days::find(number) {
+            Some(day) => Ok(vec![day]),
+            None => Err(format!("Day {} is not implemented", number)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    /// A JSON object per answer, one per line
+    Json,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Number of times to parse and solve each day
+    #[arg(short = 'n', long, default_value_t = 10)]
+    iterations: usize,
+
+    /// Print the timings as CSV rather than a table
+    #[arg(long)]
+    csv: bool,
+}
+
+// Prints every part's answer, telling whether they all had one
+fn print_report(day: &Day, report: &Report, format: Format) -> bool {
+    for outcome in report.outcomes.iter() {
+        match format {
+            Format::Text => {
+                let answer = match &outcome.answer {
+                    Ok(answer) => answer.clone(),
+                    Err(e) => format!("no answer, {}", e),
+                };
+                println!(
+                    "Day {} Part {}: {} ({:.2?})",
+                    day.number, outcome.part, answer, outcome.elapsed
+                );
+            }
+            Format::Json => println!("{}", json::outcome(day, report, outcome)),
+        }
+    }
+
+    report.outcomes.iter().all(|o| o.answer.is_ok())
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let selection = args.selection;
+    let parts = selection.parts();
+    let source = selection.input.source();
+
+    if selection.all {
+        return run_all(&source, &parts, args.format);
+    }
+
+    let day = match selection.days() {
+        Ok(days) => days[0],
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match day.solve(&source, &parts) {
+        Ok(report) => {
+            if !print_report(&day, &report, args.format) {
+                return ExitCode::FAILURE;
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Day {}: {}", day.number, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_all(source: &Source, parts: &[Part], format: Format) -> ExitCode {
+    let mut rows: Vec<Vec<String>> = vec![];
+    let mut failed = false;
+
+    for day in days::all() {
+        let row = match day.solve(source, parts) {
+            Ok(report) => {
+                if !print_report(&day, &report, format) {
+                    failed = true;
+                }
+                let cell = |part| match report.answer(part) {
+                    Some(Ok(answer)) => answer.to_owned(),
+                    Some(Err(_)) => "error".to_owned(),
+                    None => "-".to_owned(),
+                };
+                vec![
+                    day.number.to_string(),
+                    cell(Part::One),
+                    cell(Part::Two),
+                    format!("{:.2?}", report.total_elapsed()),
+                ]
+            }
+            Err(e) => {
+                failed = true;
+                eprintln!("Day {}: {}", day.number, e);
+                vec![
+                    day.number.to_string(),
+                    "error".to_owned(),
+                    "error".to_owned(),
+                    "-".to_owned(),
+                ]
+            }
+        };
+        rows.push(row);
+    }
+
+    if format == Format::Text {
+        println!();
+        println!(
+            "{}",
+            table::render(&["Day", "Part 1", "Part 2", "Time"], &rows)
+        );
+    }
+
+    if failed {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn bench_rows(bench: &Bench) -> Vec<Vec<String>> {
+    bench
+        .stages
+        .iter()
+        .map(|(stage, timing)| {
+            vec![
+                bench.day.to_string(),
+                stage.to_string(),
+                format!("{:.2?}", timing.min),
+                format!("{:.2?}", timing.median),
+                format!("{:.2?}", timing.max),
+                bench::format_throughput(bench.throughput(timing)),
+            ]
+        })
+        .collect()
+}
+
+fn run_bench(args: BenchArgs) -> ExitCode {
+    let parts = args.selection.parts();
+    let source = args.selection.input.source();
+    let days = match args.selection.days() {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut benches: Vec<Bench> = vec![];
+    let mut failed = false;
+    for day in days {
+        match bench::run(&day, &source, &parts, args.iterations) {
+            Ok(bench) => benches.push(bench),
+            Err(e) => {
+                failed = true;
+                eprintln!("Day {}: {}", day.number, e);
+            }
+        }
+    }
+
+    if args.csv {
+        println!("{}", bench::to_csv(&benches));
+    } else {
+        let rows: Vec<Vec<String>> = benches.iter().flat_map(bench_rows).collect();
+        println!(
+            "{}",
+            table::render(
+                &["Day", "Stage", "Min", "Median", "Max", "Throughput"],
+                &rows
+            )
+        );
+    }
+
+    if failed {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => run_bench(args),
+        Command::Fetch(args) => remote::fetch(args),
+        Command::Submit(args) => remote::submit(args),
+    }
+}
@@ -0,0 +1,166 @@
+use crate::days;
+use aoc_client::{Client, History, InputCache, Verdict, DEFAULT_BASE_URL};
+use aoc_core::input::Source;
+use aoc_core::runner::Part;
+use clap::Args;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+pub const DEFAULT_YEAR: u16 = 2023;
+
+// How to reach the puzzle server
+#[derive(Args)]
+pub struct ServerArgs {
+    /// Puzzle year
+    #[arg(long, default_value_t = DEFAULT_YEAR)]
+    pub year: u16,
+
+    /// Session cookie of a logged in account
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    pub session: Option<String>,
+
+    /// Root URL of the puzzle server
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    pub base_url: String,
+}
+
+impl ServerArgs {
+    pub fn client(&self) -> Result<Client, String> {
+        let Some(session) = &self.session else {
+            return Err("a session token is needed, pass --session or set AOC_SESSION".to_owned());
+        };
+        Ok(Client::new(&self.base_url, session))
+    }
+}
+
+#[derive(Args)]
+pub struct FetchArgs {
+    /// Day to download the input of
+    #[arg(long)]
+    day: u8,
+
+    #[command(flatten)]
+    server: ServerArgs,
+
+    /// Directory holding the day_<N> directories [default: the workspace]
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    /// Day the answer is for
+    #[arg(long)]
+    day: u8,
+
+    /// Part the answer is for (1 or 2)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Answer to send [default: the day's answer for its input.txt]
+    #[arg(long)]
+    answer: Option<String>,
+
+    #[command(flatten)]
+    server: ServerArgs,
+
+    /// File recording every submission [default: submissions.txt in the workspace]
+    #[arg(long)]
+    history: Option<PathBuf>,
+}
+
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+pub fn fetch(args: FetchArgs) -> ExitCode {
+    let cache = InputCache::new(args.cache_dir.unwrap_or_else(workspace_root), DEFAULT_YEAR);
+    let path = cache.path(args.server.year, args.day);
+
+    if cache.get(args.server.year, args.day).is_some() {
+        println!(
+            "Day {} input is already cached at {}",
+            args.day,
+            path.display()
+        );
+        return ExitCode::SUCCESS;
+    }
+
+    let result = args.server.client().and_then(|client| {
+        cache
+            .get_or_fetch(&client, args.server.year, args.day)
+            .map_err(|e| e.to_string())
+    });
+
+    match result {
+        Ok(_) => {
+            println!("Day {} input saved to {}", args.day, path.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Day {}: {}", args.day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn solve(day: u8, part: Part) -> Result<String, String> {
+    let Some(solution) = days::find(day) else {
+        return Err("the day is not implemented, pass --answer".to_owned());
+    };
+    let report = solution.solve(&Source::Default, &[part])?;
+    report
+        .answer(part)
+        .unwrap()
+        .map(|answer| answer.to_owned())
+        .map_err(|e| format!("part {} has no answer, {}", part, e))
+}
+
+pub fn submit(args: SubmitArgs) -> ExitCode {
+    let part = Part::try_from(args.part).unwrap();
+    let history_path = args
+        .history
+        .unwrap_or_else(|| workspace_root().join("submissions.txt"));
+
+    let result = args
+        .answer
+        .map_or_else(|| solve(args.day, part), Ok)
+        .and_then(|answer| {
+            let client = args.server.client()?;
+            let mut history = History::load(&history_path).map_err(|e| e.to_string())?;
+            let verdict = history
+                .submit(&client, args.server.year, args.day, args.part, &answer)
+                .map_err(|e| e.to_string())?;
+            Ok((answer, verdict))
+        });
+
+    let (answer, verdict) = match result {
+        Ok(submitted) => submitted,
+        Err(e) => {
+            eprintln!("Day {} Part {}: {}", args.day, part, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let message = match verdict {
+        Verdict::Correct => "is right",
+        Verdict::Wrong => "is wrong",
+        Verdict::TooHigh => "is too high",
+        Verdict::TooLow => "is too low",
+        Verdict::RateLimited(_) => "was not judged, an answer was sent too recently",
+        Verdict::WrongLevel => "was not judged, the part is solved or still locked",
+        Verdict::Unknown => "got a response that could not be understood",
+    };
+    println!("Day {} Part {}: {} {}", args.day, part, answer, message);
+    if let Verdict::RateLimited(Some(wait)) = verdict {
+        println!("Try again in {}s", wait.as_secs());
+    }
+
+    if verdict != Verdict::Correct {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,175 @@
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+use aoc_core::ParseError;
+use std::cmp::Reverse;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+const ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// The words that count as digits on top of 0-9 themselves
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Lexicon {
+    words: Vec<(String, u32)>,
+    case_insensitive: bool,
+}
+
+impl Lexicon {
+    pub fn digits_only() -> Self {
+        Lexicon::default()
+    }
+
+    pub fn english() -> Self {
+        ENGLISH
+            .iter()
+            .zip(1..)
+            .fold(Lexicon::digits_only(), |lexicon, (word, value)| {
+                lexicon.with_word(word, value)
+            })
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+        text.parse::<Lexicon>()
+            .map_err(|e| e.diagnostic(&text, &path.display().to_string()))
+    }
+
+    pub fn with_word(mut self, word: &str, value: u32) -> Self {
+        if !self.words.iter().any(|(w, _)| w == word) {
+            self.words.push((word.to_owned(), value));
+        }
+        self
+    }
+
+    // Also matches every word spelled backwards
+    pub fn with_reversed(self) -> Self {
+        let reversed: Vec<(String, u32)> = self
+            .words
+            .iter()
+            .map(|(word, value)| (word.chars().rev().collect(), *value))
+            .collect();
+        reversed.iter().fold(self, |lexicon, (word, value)| {
+            lexicon.with_word(word, *value)
+        })
+    }
+
+    // Only folds ASCII letters, other characters must match exactly
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+
+    pub fn words(&self) -> &[(String, u32)] {
+        &self.words
+    }
+
+    pub fn matcher(&self) -> Matcher {
+        let mut patterns: Vec<String> = (0..10).map(|d: u32| d.to_string()).collect();
+        let mut values: Vec<u32> = (0..10).collect();
+        for (word, value) in self.words.iter() {
+            patterns.push(word.clone());
+            values.push(*value);
+        }
+
+        let automaton = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::Standard)
+            .ascii_case_insensitive(self.case_insensitive)
+            .build(&patterns)
+            .unwrap();
+
+        Matcher { automaton, values }
+    }
+}
+
+// One `<word> <digit>` entry per line, `set case-insensitive` and
+// `set reversed` switch on those options, # starts a comment
+impl FromStr for Lexicon {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lexicon = Lexicon::digits_only();
+        let mut reversed = false;
+
+        for line in s.lines() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some((first, second)) = line.split_once(' ') else {
+                return Err(ParseError::at_end(line, "a digit").in_context(s, line));
+            };
+            let second = second.trim();
+
+            if first == "set" {
+                match second {
+                    "case-insensitive" => lexicon = lexicon.case_insensitive(),
+                    "reversed" => reversed = true,
+                    _ => {
+                        return Err(ParseError::new(
+                            s,
+                            second,
+                            "`case-insensitive` or `reversed`",
+                        ))
+                    }
+                }
+                continue;
+            }
+
+            let value = match second.parse::<u32>() {
+                Ok(value) if value < 10 => value,
+                _ => return Err(ParseError::new(s, second, "a digit from 0 to 9")),
+            };
+            if lexicon.words.iter().any(|(w, _)| w == first) {
+                return Err(ParseError::new(
+                    s,
+                    first,
+                    "a word not already in the lexicon",
+                ));
+            }
+            lexicon = lexicon.with_word(first, value);
+        }
+
+        if reversed {
+            lexicon = lexicon.with_reversed();
+        }
+        Ok(lexicon)
+    }
+}
+
+// Finds every digit and word of a lexicon in a single pass over a line
+pub struct Matcher {
+    automaton: AhoCorasick,
+    // Digit of each pattern, by pattern index
+    values: Vec<u32>,
+}
+
+impl Matcher {
+    // First and last digit of the line as a two digit number. Overlapping
+    // words such as `twone` are all reported, and of words starting at the
+    // same place the longest wins.
+    pub fn calibration_value(&self, line: &str) -> Option<u32> {
+        let mut first: Option<(usize, usize, u32)> = None;
+        let mut last: Option<(usize, usize, u32)> = None;
+
+        for m in self.automaton.find_overlapping_iter(line) {
+            let found = (m.start(), m.len(), self.values[m.pattern().as_usize()]);
+            let earlier = |(start, len, _): (usize, usize, u32)| {
+                (found.0, Reverse(found.1)) < (start, Reverse(len))
+            };
+            let later = |(start, len, _): (usize, usize, u32)| (found.0, found.1) > (start, len);
+
+            if first.is_none_or(earlier) {
+                first = Some(found);
+            }
+            if last.is_none_or(later) {
+                last = Some(found);
+            }
+        }
+
+        Some(first?.2 * 10 + last?.2)
+    }
+}
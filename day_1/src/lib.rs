@@ -0,0 +1,39 @@
+use aoc_core::{ParseError, Solution};
+
+mod lexicon;
+
+pub use lexicon::{Lexicon, Matcher};
+
+// Lines without any digit have no value and add nothing
+pub fn calibration_sum(lines: &[String], lexicon: &Lexicon) -> u32 {
+    let matcher = lexicon.matcher();
+    lines
+        .iter()
+        .filter_map(|line| matcher.calibration_value(line))
+        .sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(aoc_core::lines(input).map(str::to_owned).collect())
+    }
+
+    // Sum of calibration values using digits only
+    fn part_1(lines: &Self::Input) -> u32 {
+        calibration_sum(lines, &Lexicon::digits_only())
+    }
+
+    // Sum of calibration values counting spelled out digits too
+    fn part_2(lines: &Self::Input) -> u32 {
+        calibration_sum(lines, &Lexicon::english())
+    }
+}
@@ -0,0 +1,44 @@
+use aoc_core::cli::InputArgs;
+use aoc_core::input;
+use aoc_core::runner::Part;
+use aoc_core::Solution;
+use clap::Parser;
+use day_1::{Day1, Lexicon};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Solve part 2 with the digit words of this lexicon file instead
+    #[arg(long)]
+    lexicon: Option<PathBuf>,
+}
+
+fn solve_with(lexicon: &Path, args: &InputArgs) -> Result<u32, String> {
+    let lexicon = Lexicon::load(lexicon)?;
+    let loaded = input::load(&args.source(), Path::new(Day1::DIR), &[Part::Two])?;
+    let lines = Day1::parse(&loaded[0].text).unwrap();
+
+    Ok(day_1::calibration_sum(&lines, &lexicon))
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let Some(lexicon) = &args.lexicon else {
+        return aoc_core::cli::run::<Day1>(&args.input);
+    };
+
+    match solve_with(lexicon, &args.input) {
+        Ok(sum) => {
+            println!("{}", sum);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
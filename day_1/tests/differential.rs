@@ -0,0 +1,320 @@
+// Checks the Rust solution against the original shell and JavaScript ones on
+// random calibration documents. Scripts whose tools are not installed are
+// skipped. Documents come from a fixed seed unless DAY_1_DIFF_SEED gives
+// another, or `random` for a new one each run, and DAY_1_DIFF_ROUNDS sets how
+// many documents are tried.
+use aoc_core::runner::Part;
+use aoc_core::Solution;
+use day_1::Day1;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// Words sharing letters, which a scan that skips past a match would miss
+const OVERLAPS: [&str; 9] = [
+    "twone",
+    "oneight",
+    "threeight",
+    "fiveight",
+    "nineight",
+    "eightwo",
+    "eighthree",
+    "sevenine",
+    "twoneight",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Digit(u8),
+    Word(&'static str),
+    Noise(String),
+}
+
+fn render(line: &[Token]) -> String {
+    line.iter()
+        .map(|token| match token {
+            Token::Digit(d) => d.to_string(),
+            Token::Word(w) => w.to_string(),
+            Token::Noise(n) => n.clone(),
+        })
+        .collect()
+}
+
+// Every line has a digit or a word, the scripts have no answer otherwise
+fn is_valid(line: &[Token]) -> bool {
+    line.iter().any(|t| !matches!(t, Token::Noise(_)))
+}
+
+fn random_noise(rng: &mut fastrand::Rng) -> Token {
+    let len = rng.usize(1..6);
+    Token::Noise((0..len).map(|_| rng.lowercase()).collect())
+}
+
+fn random_line(rng: &mut fastrand::Rng) -> Vec<Token> {
+    // A lone digit is used as both the first and the last
+    if rng.u8(0..8) == 0 {
+        return vec![
+            random_noise(rng),
+            Token::Digit(rng.u8(1..10)),
+            random_noise(rng),
+        ];
+    }
+
+    loop {
+        let line: Vec<Token> = (0..rng.usize(1..7))
+            .map(|_| match rng.u8(0..4) {
+                0 => Token::Digit(rng.u8(1..10)),
+                1 => Token::Word(WORDS[rng.usize(..WORDS.len())]),
+                2 => Token::Word(OVERLAPS[rng.usize(..OVERLAPS.len())]),
+                _ => random_noise(rng),
+            })
+            .collect();
+        if is_valid(&line) {
+            return line;
+        }
+    }
+}
+
+type Solve = Box<dyn Fn(&str) -> Result<String, String>>;
+
+struct Implementation {
+    name: &'static str,
+    part: Part,
+    solve: Solve,
+}
+
+const DEFAULT_SEED: u64 = 2023;
+
+// Written past the test harness, which only captures the print macros, so
+// that what a passing run didn't check is still shown
+fn notice(message: &str) {
+    let line = format!("differential: {}\n", message);
+    io::stderr().write_all(line.as_bytes()).unwrap();
+}
+
+fn installed(tool: &str) -> bool {
+    Command::new("sh")
+        .arg("-c")
+        .arg(format!("command -v {}", tool))
+        .output()
+        .is_ok_and(|o| o.status.success())
+}
+
+// Runs one of the day's scripts, which read input.txt from where they run
+fn run_script(program: &str, script: &str, input: &str) -> Result<String, String> {
+    let dir = tempfile::tempdir().map_err(|e| e.to_string())?;
+    fs::write(dir.path().join("input.txt"), input).map_err(|e| e.to_string())?;
+
+    let output = Command::new(program)
+        .arg(Path::new(Day1::DIR).join(script))
+        .current_dir(dir.path())
+        .output()
+        .map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+
+    // bc prints nothing for an empty sum
+    let answer = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+    if answer.is_empty() {
+        return Ok("0".to_owned());
+    }
+    Ok(answer)
+}
+
+fn rust(part: Part) -> Implementation {
+    Implementation {
+        name: "rust",
+        part,
+        solve: Box::new(move |input| {
+            let lines = Day1::parse(input).map_err(|e| e.to_string())?;
+            Ok(match part {
+                Part::One => Day1::part_1(&lines).to_string(),
+                Part::Two => Day1::part_2(&lines).to_string(),
+            })
+        }),
+    }
+}
+
+fn available_implementations() -> Vec<Implementation> {
+    let mut implementations = vec![rust(Part::One), rust(Part::Two)];
+
+    if ["sed", "xargs", "tr", "bc"].iter().all(|t| installed(t)) {
+        implementations.push(Implementation {
+            name: "sh",
+            part: Part::One,
+            solve: Box::new(|input| run_script("sh", "solution-part-1.sh", input)),
+        });
+    } else {
+        notice("skipping solution-part-1.sh, it needs sed, xargs, tr and bc");
+    }
+
+    if installed("node") {
+        implementations.push(Implementation {
+            name: "js",
+            part: Part::Two,
+            solve: Box::new(|input| run_script("node", "solution-part-2.js", input)),
+        });
+    } else {
+        notice("skipping solution-part-2.js, it needs node");
+    }
+
+    implementations
+}
+
+// Every implementation's answer for the part, if they don't all agree
+fn disagreement(
+    implementations: &[Implementation],
+    part: Part,
+    input: &str,
+) -> Option<Vec<(&'static str, Result<String, String>)>> {
+    let results: Vec<(&'static str, Result<String, String>)> = implementations
+        .iter()
+        .filter(|i| i.part == part)
+        .map(|i| (i.name, (i.solve)(input)))
+        .collect();
+
+    if results.windows(2).all(|w| w[0].1 == w[1].1) {
+        return None;
+    }
+    Some(results)
+}
+
+// Removes tokens and trims noise for as long as the line keeps failing
+fn shrink<F>(mut line: Vec<Token>, fails: F) -> Vec<Token>
+where
+    F: Fn(&[Token]) -> bool,
+{
+    loop {
+        let mut candidates: Vec<Vec<Token>> = vec![];
+        for i in 0..line.len() {
+            let mut removed = line.clone();
+            removed.remove(i);
+            candidates.push(removed);
+
+            if let Token::Noise(noise) = &line[i] {
+                if noise.len() > 1 {
+                    let mut trimmed = line.clone();
+                    trimmed[i] = Token::Noise(noise[1..].to_owned());
+                    candidates.push(trimmed);
+                }
+            }
+        }
+
+        let Some(smaller) = candidates.into_iter().find(|c| is_valid(c) && fails(c)) else {
+            return line;
+        };
+        line = smaller;
+    }
+}
+
+// Describes the first disagreement on the document, narrowed down to a
+// single line and shrunk when one line alone reproduces it
+fn find_mismatch(implementations: &[Implementation], document: &[Vec<Token>]) -> Option<String> {
+    let input: String = document.iter().map(|l| render(l) + "\n").collect();
+
+    for &part in Part::BOTH {
+        let Some(results) = disagreement(implementations, part, &input) else {
+            continue;
+        };
+        let fails =
+            |line: &[Token]| disagreement(implementations, part, &(render(line) + "\n")).is_some();
+
+        let Some(line) = document.iter().find(|l| fails(l)) else {
+            return Some(format!(
+                "part {} disagrees on the whole document but on no single line: {:?}\n{}",
+                part, results, input
+            ));
+        };
+        let minimal = render(&shrink(line.clone(), fails));
+        let results = disagreement(implementations, part, &(minimal.clone() + "\n")).unwrap();
+        return Some(format!(
+            "part {} disagrees on `{}`: {:?}",
+            part, minimal, results
+        ));
+    }
+
+    None
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    env::var(name)
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default)
+}
+
+#[test]
+fn implementations_agree_on_random_lines() {
+    let implementations = available_implementations();
+    let compared: Vec<Part> = Part::BOTH
+        .iter()
+        .copied()
+        .filter(|&part| implementations.iter().filter(|i| i.part == part).count() > 1)
+        .collect();
+    for part in Part::BOTH.iter().filter(|p| !compared.contains(p)) {
+        notice(&format!(
+            "part {} has nothing to compare the Rust solution with and is not checked",
+            part
+        ));
+    }
+    if compared.is_empty() {
+        return;
+    }
+
+    let seed = match env::var("DAY_1_DIFF_SEED").as_deref() {
+        Ok("random") => {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+            notice(&format!("random seed {}", now.as_secs()));
+            now.as_secs()
+        }
+        _ => env_or("DAY_1_DIFF_SEED", DEFAULT_SEED),
+    };
+    let rounds = env_or("DAY_1_DIFF_ROUNDS", 10);
+    let mut rng = fastrand::Rng::with_seed(seed);
+
+    for _ in 0..rounds {
+        let document: Vec<Vec<Token>> = (0..50).map(|_| random_line(&mut rng)).collect();
+        if let Some(mismatch) = find_mismatch(&implementations, &document) {
+            panic!("{}\nreplay with DAY_1_DIFF_SEED={}", mismatch, seed);
+        }
+    }
+}
+
+#[test]
+fn mismatches_shrink_to_a_minimal_line() {
+    // Stands in for a solution that forgot about spelled out digits
+    let digits_only = Implementation {
+        name: "digits only",
+        part: Part::Two,
+        solve: Box::new(|input| {
+            let lines = Day1::parse(input).map_err(|e| e.to_string())?;
+            Ok(Day1::part_1(&lines).to_string())
+        }),
+    };
+    let implementations = vec![rust(Part::Two), digits_only];
+    let document = vec![
+        vec![Token::Noise("xx".to_owned()), Token::Digit(1)],
+        vec![
+            Token::Noise("ab".to_owned()),
+            Token::Digit(3),
+            Token::Noise("cd".to_owned()),
+            Token::Digit(7),
+            Token::Word("twone"),
+        ],
+    ];
+
+    let mismatch = find_mismatch(&implementations, &document).unwrap();
+    assert!(
+        mismatch.starts_with("part 2 disagrees on `twone`"),
+        "{}",
+        mismatch
+    );
+}
@@ -0,0 +1,52 @@
+use day_1::Lexicon;
+use std::path::Path;
+
+fn value(lexicon: &Lexicon, line: &str) -> Option<u32> {
+    lexicon.matcher().calibration_value(line)
+}
+
+#[test]
+fn overlapping_words_count_from_both_ends() {
+    let english = Lexicon::english();
+    assert_eq!(value(&english, "twone"), Some(21));
+    assert_eq!(value(&english, "eightwo"), Some(82));
+    assert_eq!(value(&english, "xtwone3four"), Some(24));
+    assert_eq!(value(&english, "treb7uchet"), Some(77));
+    assert_eq!(value(&english, "nodigits"), None);
+    assert_eq!(value(&Lexicon::digits_only(), "two1nine"), Some(11));
+}
+
+#[test]
+fn options_extend_the_matched_words() {
+    let lexicon = Lexicon::english().with_word("zero", 0).with_reversed();
+    assert_eq!(value(&lexicon, "zeroabceno"), Some(1));
+    assert_eq!(value(&lexicon, "ONE2"), Some(22));
+    assert_eq!(value(&lexicon.case_insensitive(), "ONE2"), Some(12));
+}
+
+#[test]
+fn lexicon_files_match_the_built_in_lexicon() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("lexicons");
+    assert_eq!(
+        Lexicon::load(dir.join("english.txt")).unwrap(),
+        Lexicon::english()
+    );
+
+    let french = Lexicon::load(dir.join("french.txt")).unwrap();
+    assert_eq!(value(&french, "Deux4zéroNEUF"), Some(29));
+}
+
+#[test]
+fn lexicon_errors_point_at_the_entry() {
+    let error = "# words\nset reversed\none 1\ntwo 12\n"
+        .parse::<Lexicon>()
+        .unwrap_err();
+    assert_eq!((error.line, error.column), (4, 5));
+    assert_eq!(error.expected, "a digit from 0 to 9");
+
+    let error = "one 1\none 2\n".parse::<Lexicon>().unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.found.as_str()),
+        (2, 1, "one")
+    );
+}
@@ -0,0 +1,405 @@
+use crate::{Game, Session};
+use serde_json::{json, Value};
+use std::fmt;
+use std::str::FromStr;
+
+// Largest number of cubes of a color a prior allows unless told otherwise
+pub const DEFAULT_MAX: u32 = 25;
+// Largest number of cubes of a color a prior can allow, keeping the search
+// over bags small
+pub const LARGEST_MAX: u32 = 200;
+
+// Belief about the number of cubes of each color before seeing any draw,
+// the same for every color and independent between colors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Prior {
+    // Every count from 0 to max alike
+    Uniform { max: u32 },
+    // Poisson around a mean, cut off at max
+    Poisson { mean: f64, max: u32 },
+}
+
+impl Prior {
+    pub fn max(&self) -> u32 {
+        match *self {
+            Prior::Uniform { max } | Prior::Poisson { max, .. } => max,
+        }
+    }
+
+    // Unnormalized, which the posterior doesn't need
+    fn log_weight(&self, n: u32, ln_factorial: &[f64]) -> f64 {
+        match *self {
+            Prior::Uniform { .. } => 0.0,
+            Prior::Poisson { mean, .. } => n as f64 * mean.ln() - ln_factorial[n as usize],
+        }
+    }
+}
+
+// `uniform[:MAX]` or `poisson:MEAN[:MAX]`
+impl FromStr for Prior {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split(':').collect();
+        let max = |field: Option<&&str>| match field {
+            Some(max) => match max.parse::<u32>() {
+                Ok(n) if n <= LARGEST_MAX => Ok(n),
+                Ok(_) => Err(format!(
+                    "`{}` is too many cubes, a prior allows at most {}",
+                    max, LARGEST_MAX
+                )),
+                Err(_) => Err(format!("`{}` is not a cube count", max)),
+            },
+            None => Ok(DEFAULT_MAX),
+        };
+
+        match fields[0] {
+            "uniform" if fields.len() <= 2 => Ok(Prior::Uniform {
+                max: max(fields.get(1))?,
+            }),
+            "poisson" if fields.len() == 2 || fields.len() == 3 => {
+                let mean = fields[1]
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|&m| m > 0.0 && m.is_finite())
+                    .ok_or_else(|| format!("`{}` is not a positive finite mean", fields[1]))?;
+                Ok(Prior::Poisson {
+                    mean,
+                    max: max(fields.get(2))?,
+                })
+            }
+            _ => Err(format!(
+                "`{}` is not a prior, expected `uniform[:MAX]` or `poisson:MEAN[:MAX]`",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Prior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Prior::Uniform { max } => write!(f, "uniform:{}", max),
+            Prior::Poisson { mean, max } => write!(f, "poisson:{}:{}", mean, max),
+        }
+    }
+}
+
+// How the cubes of a draw come out of the bag. Either way the whole bag is
+// there again for the next draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Model {
+    // Each cube is put back before the next one is taken, so a draw is
+    // multinomial and may show more cubes of a color than the bag holds
+    Multinomial,
+    // The cubes of a draw are taken together, so a draw is hypergeometric
+    Hypergeometric,
+}
+
+impl Model {
+    // Fewest cubes of a color the bag can hold, given the most shown at once
+    fn least(&self, shown: u32) -> u32 {
+        match self {
+            Model::Multinomial => shown.min(1),
+            Model::Hypergeometric => shown,
+        }
+    }
+}
+
+impl FromStr for Model {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "multinomial" => Ok(Model::Multinomial),
+            "hypergeometric" => Ok(Model::Hypergeometric),
+            _ => Err(format!(
+                "`{}` is not a model, expected `multinomial` or `hypergeometric`",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Model {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Model::Multinomial => "multinomial",
+            Model::Hypergeometric => "hypergeometric",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorEstimate {
+    pub color: String,
+    // Posterior mean of the number of cubes
+    pub mean: f64,
+    // Equal-tailed credible interval, both ends included
+    pub interval: (u32, u32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub session: u32,
+    // The single most likely bag
+    pub map_bag: Game,
+    pub colors: Vec<ColorEstimate>,
+}
+
+impl Estimate {
+    pub fn to_json(&self) -> Value {
+        let map_bag: serde_json::Map<String, Value> = self
+            .map_bag
+            .cubes()
+            .map(|(color, count)| (color.to_owned(), json!(count)))
+            .collect();
+        let colors: Vec<Value> = self
+            .colors
+            .iter()
+            .map(|c| {
+                json!({
+                    "color": c.color,
+                    "mean": c.mean,
+                    "low": c.interval.0,
+                    "high": c.interval.1,
+                })
+            })
+            .collect();
+
+        json!({
+            "session": self.session,
+            "map_bag": map_bag,
+            "colors": colors,
+        })
+    }
+}
+
+// Estimates what was in the bag of a session from its draws, the draws coming
+// out of the bag as the model says
+pub struct Estimator {
+    prior: Prior,
+    model: Model,
+    colors: Vec<String>,
+    // Probability mass of the credible intervals
+    level: f64,
+    ln_factorial: Vec<f64>,
+}
+
+impl Estimator {
+    pub fn build(prior: Prior, model: Model, colors: &[&str], level: f64) -> Self {
+        let largest = prior.max() as usize * colors.len().max(1);
+        let mut ln_factorial = vec![0.0; largest + 1];
+        for n in 1..=largest {
+            ln_factorial[n] = ln_factorial[n - 1] + (n as f64).ln();
+        }
+
+        Estimator {
+            prior,
+            model,
+            colors: colors.iter().map(|c| c.to_string()).collect(),
+            level,
+            ln_factorial,
+        }
+    }
+
+    fn ln_choose(&self, n: u32, k: u32) -> f64 {
+        let f = &self.ln_factorial;
+        f[n as usize] - f[k as usize] - f[(n - k) as usize]
+    }
+
+    // The likelihood of the draws splits into a factor per color, depending
+    // on its count alone, and a factor of the bag's total. Both are logs,
+    // NEG_INFINITY where the bag can't give the draws, and leaving out the
+    // factors that are the same for every bag.
+    fn color_factor(&self, draws: &[Vec<u32>], color: usize, n: u32) -> f64 {
+        draws.iter().map(|draw| self.ways(n, draw[color])).sum()
+    }
+
+    fn total_factor(&self, draws: &[Vec<u32>], total: u32) -> f64 {
+        draws
+            .iter()
+            .map(|draw| match self.ways(total, draw.iter().sum()) {
+                ways if ways == f64::NEG_INFINITY => ways,
+                ways => -ways,
+            })
+            .sum()
+    }
+
+    // Log of how many ways `k` cubes are drawn out of `n`, up to a factor
+    // depending on `k` alone
+    fn ways(&self, n: u32, k: u32) -> f64 {
+        match (self.model, k) {
+            (_, 0) => 0.0,
+            (_, k) if n == 0 || (self.model == Model::Hypergeometric && k > n) => f64::NEG_INFINITY,
+            (Model::Multinomial, k) => k as f64 * (n as f64).ln(),
+            (Model::Hypergeometric, k) => self.ln_choose(n, k),
+        }
+    }
+
+    pub fn estimate(&self, session: &Session) -> Result<Estimate, String> {
+        if let Some((color, _)) = session
+            .maximum_possible_game()
+            .cubes()
+            .find(|(c, _)| !self.colors.iter().any(|known| known == c))
+        {
+            return Err(format!(
+                "Game {} draws {}, which is not estimated",
+                session.id, color
+            ));
+        }
+
+        let draws: Vec<Vec<u32>> = session
+            .games
+            .iter()
+            .map(|g| self.colors.iter().map(|c| g.count(c)).collect())
+            .collect();
+        // No bag with fewer cubes of a color than a draw showed is possible
+        let least: Vec<u32> = self
+            .colors
+            .iter()
+            .map(|c| self.model.least(session.maximum_possible_game().count(c)))
+            .collect();
+        let max = self.prior.max();
+        if least.iter().any(|&n| n > max) {
+            return Err(format!(
+                "Game {} draws more cubes of a color than the prior allows",
+                session.id
+            ));
+        }
+
+        // The posterior of a bag is a product of a weight per color and a
+        // weight of its total, so bags are summed over or maximized over one
+        // color at a time rather than one bag at a time
+        let weights: Vec<Vec<f64>> = (0..self.colors.len())
+            .map(|i| {
+                (0..=max)
+                    .map(|n| match n < least[i] {
+                        true => f64::NEG_INFINITY,
+                        false => {
+                            self.prior.log_weight(n, &self.ln_factorial)
+                                + self.color_factor(&draws, i, n)
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let totals: Vec<f64> = (0..=max * self.colors.len() as u32)
+            .map(|total| self.total_factor(&draws, total))
+            .collect();
+        let best = later(&weights, &totals, f64::max);
+        let sums = later(&weights, &totals, ln_add);
+
+        // Among ties, up to rounding, the fewest cubes of the first colors
+        let mut map = vec![];
+        let mut held = 0;
+        for (i, weight) in weights.iter().enumerate() {
+            let score = |n: usize| weight[n] + best[i + 1][held + n];
+            let n = (0..weight.len()).fold(0, |top, n| match score(n) > score(top) + 1e-9 {
+                true => n,
+                false => top,
+            });
+            map.push(n as u32);
+            held += n;
+        }
+        let map_bag = Game::build_full(
+            &self
+                .colors
+                .iter()
+                .zip(map.iter())
+                .map(|(c, &n)| (c.as_str(), n))
+                .collect::<Vec<(&str, u32)>>(),
+        );
+
+        // Marginal posterior of each color's count, from the colors before it,
+        // over how many cubes they hold, and the colors after it
+        let mut earlier = vec![f64::NEG_INFINITY; totals.len()];
+        earlier[0] = 0.0;
+        let mut marginals = vec![];
+        for (i, weight) in weights.iter().enumerate() {
+            let mut marginal = vec![0.0; weight.len()];
+            let mut next = vec![f64::NEG_INFINITY; totals.len()];
+            for (held, &before) in earlier.iter().enumerate() {
+                if before == f64::NEG_INFINITY {
+                    continue;
+                }
+                for (n, &w) in weight.iter().enumerate() {
+                    marginal[n] += (before + w + sums[i + 1][held + n] - sums[0][0]).exp();
+                    next[held + n] = ln_add(next[held + n], before + w);
+                }
+            }
+            marginals.push(marginal);
+            earlier = next;
+        }
+
+        let tail = (1.0 - self.level) / 2.0;
+        let colors = self
+            .colors
+            .iter()
+            .zip(marginals.iter())
+            .map(|(color, marginal)| {
+                let total: f64 = marginal.iter().sum();
+                let mean = marginal
+                    .iter()
+                    .enumerate()
+                    .map(|(n, w)| n as f64 * w)
+                    .sum::<f64>()
+                    / total;
+                let quantile = |q: f64| {
+                    let mut cumulative = 0.0;
+                    for (n, w) in marginal.iter().enumerate() {
+                        cumulative += w / total;
+                        if *w > 0.0 && cumulative >= q - 1e-12 {
+                            return n as u32;
+                        }
+                    }
+                    max
+                };
+
+                ColorEstimate {
+                    color: color.clone(),
+                    mean,
+                    interval: (quantile(tail), quantile(1.0 - tail)),
+                }
+            })
+            .collect();
+
+        Ok(Estimate {
+            session: session.id,
+            map_bag,
+            colors,
+        })
+    }
+}
+
+// For the colors from each one on, and every count of cubes of the colors
+// before it, the weights of the ways to fill the rest of the bag combined:
+// summed for the posterior mass or maximized for the most likely bag
+fn later(weights: &[Vec<f64>], totals: &[f64], combine: fn(f64, f64) -> f64) -> Vec<Vec<f64>> {
+    let mut later = vec![totals.to_vec()];
+    for weight in weights.iter().rev() {
+        let after = later.last().unwrap();
+        let here = (0..totals.len())
+            .map(|held| {
+                weight
+                    .iter()
+                    .zip(after.iter().skip(held))
+                    .map(|(w, a)| w + a)
+                    .fold(f64::NEG_INFINITY, combine)
+            })
+            .collect();
+        later.push(here);
+    }
+    later.reverse();
+    later
+}
+
+// ln(e^a + e^b), staying in logs
+fn ln_add(a: f64, b: f64) -> f64 {
+    let (high, low) = if a > b { (a, b) } else { (b, a) };
+    match low == f64::NEG_INFINITY {
+        true => high,
+        false => high + (low - high).exp().ln_1p(),
+    }
+}
@@ -0,0 +1,460 @@
+use crate::{Game, Session};
+use aoc_core::ParseError;
+
+// A condition on a session, such as
+//
+//     any(green > 10) and power > 500
+//
+// Sessions have `id`, `power` (of their smallest bag), `draws` (their number)
+// and `max.<color>`, `min.<color>` and `total.<color>` over their draws.
+// Within `any(...)` and `all(...)`, which hold when some or every draw meets
+// the condition, `<color>` is the count drawn and `cubes` the draw's size.
+// Numbers combine with `+`, `-` and `*`, compare with `<`, `<=`, `>`, `>=`,
+// `==` and `!=`, and conditions with `and`, `or`, `not` and parentheses. A
+// comparison with a number too large for 64 bits is false.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    condition: Expr,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Field {
+    Id,
+    Power,
+    Draws,
+    Max(String),
+    Min(String),
+    Total(String),
+    // Only within a draw
+    Count(String),
+    Cubes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expr {
+    Int(i64),
+    Bool(bool),
+    Field(Field),
+    Binary(Box<Expr>, Op, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    // `all` when true, `any` otherwise
+    Draws(bool, Box<Expr>),
+}
+
+impl Expr {
+    // None once the number overflows
+    fn number(&self, session: &Session, draw: Option<&Game>) -> Option<i64> {
+        match self {
+            Expr::Int(n) => Some(*n),
+            Expr::Field(field) => field.value(session, draw),
+            Expr::Binary(left, op, right) => {
+                let (l, r) = (left.number(session, draw)?, right.number(session, draw)?);
+                match op {
+                    Op::Add => l.checked_add(r),
+                    Op::Sub => l.checked_sub(r),
+                    _ => l.checked_mul(r),
+                }
+            }
+            _ => unreachable!("conditions are not numbers"),
+        }
+    }
+
+    fn holds(&self, session: &Session, draw: Option<&Game>) -> bool {
+        match self {
+            Expr::Bool(b) => *b,
+            Expr::Binary(left, op, right) => {
+                let (Some(l), Some(r)) = (left.number(session, draw), right.number(session, draw))
+                else {
+                    return false;
+                };
+                match op {
+                    Op::Lt => l < r,
+                    Op::Le => l <= r,
+                    Op::Gt => l > r,
+                    Op::Ge => l >= r,
+                    Op::Eq => l == r,
+                    _ => l != r,
+                }
+            }
+            Expr::And(left, right) => left.holds(session, draw) && right.holds(session, draw),
+            Expr::Or(left, right) => left.holds(session, draw) || right.holds(session, draw),
+            Expr::Not(inner) => !inner.holds(session, draw),
+            Expr::Draws(true, inner) => session.games.iter().all(|g| inner.holds(session, Some(g))),
+            Expr::Draws(false, inner) => {
+                session.games.iter().any(|g| inner.holds(session, Some(g)))
+            }
+            _ => unreachable!("numbers are not conditions"),
+        }
+    }
+}
+
+impl Field {
+    fn value(&self, session: &Session, draw: Option<&Game>) -> Option<i64> {
+        let counts = |color: &String| -> Vec<i64> {
+            session
+                .games
+                .iter()
+                .map(|g| g.count(color) as i64)
+                .collect()
+        };
+        let value = match self {
+            Field::Id => session.id as i64,
+            Field::Power => i64::try_from(session.maximum_possible_game().power()?).ok()?,
+            Field::Draws => session.games.len() as i64,
+            Field::Max(color) => counts(color).into_iter().max().unwrap_or(0),
+            Field::Min(color) => counts(color).into_iter().min().unwrap_or(0),
+            Field::Total(color) => counts(color).into_iter().sum(),
+            Field::Count(color) => draw.map_or(0, |g| g.count(color) as i64),
+            Field::Cubes => draw.map_or(0, |g| g.cubes().map(|(_, n)| n as i64).sum()),
+        };
+        Some(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Type {
+    Number,
+    Condition,
+}
+
+// A parsed expression with its type and the text it was parsed from
+struct Node<'a> {
+    expr: Expr,
+    ty: Type,
+    text: &'a str,
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    tokens: Vec<&'a str>,
+    pos: usize,
+    colors: &'a [&'a str],
+    in_draw: bool,
+}
+
+fn tokenize(source: &str) -> Result<Vec<&str>, ParseError> {
+    let mut tokens: Vec<&str> = vec![];
+    let mut rest = source.trim_start();
+
+    while let Some(c) = rest.chars().next() {
+        let len = if c.is_ascii_alphanumeric() || c == '_' {
+            rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+                .unwrap_or(rest.len())
+        } else if ["<=", ">=", "==", "!="]
+            .iter()
+            .any(|op| rest.starts_with(op))
+        {
+            2
+        } else if "()<>+-*".contains(c) {
+            1
+        } else {
+            let found = &rest[..c.len_utf8()];
+            return Err(ParseError::new(
+                source,
+                found,
+                "a number, field or operator",
+            ));
+        };
+
+        tokens.push(&rest[..len]);
+        rest = rest[len..].trim_start();
+    }
+
+    Ok(tokens)
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    // The current token, or the empty end of the source
+    fn here(&self) -> &'a str {
+        self.peek().unwrap_or(&self.source[self.source.len()..])
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, token: &str) -> Result<&'a str, ParseError> {
+        let here = self.here();
+        if !self.eat(token) {
+            return Err(ParseError::new(self.source, here, &format!("`{}`", token)));
+        }
+        Ok(here)
+    }
+
+    // The source text from the start of `first` to the end of `last`
+    fn span(&self, first: &str, last: &str) -> &'a str {
+        let base = self.source.as_ptr() as usize;
+        let start = first.as_ptr() as usize - base;
+        let end = last.as_ptr() as usize - base + last.len();
+        &self.source[start..end]
+    }
+
+    fn typed(&self, node: Node<'a>, ty: Type) -> Result<Expr, ParseError> {
+        if node.ty != ty {
+            let expected = match ty {
+                Type::Number => "a number",
+                Type::Condition => "a condition",
+            };
+            return Err(ParseError::new(self.source, node.text, expected));
+        }
+        Ok(node.expr)
+    }
+
+    fn or(&mut self) -> Result<Node<'a>, ParseError> {
+        let mut left = self.and()?;
+        while self.eat("or") {
+            let right = self.and()?;
+            let text = self.span(left.text, right.text);
+            let (l, r) = (
+                self.typed(left, Type::Condition)?,
+                self.typed(right, Type::Condition)?,
+            );
+            left = Node {
+                expr: Expr::Or(Box::new(l), Box::new(r)),
+                ty: Type::Condition,
+                text,
+            };
+        }
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Result<Node<'a>, ParseError> {
+        let mut left = self.not()?;
+        while self.eat("and") {
+            let right = self.not()?;
+            let text = self.span(left.text, right.text);
+            let (l, r) = (
+                self.typed(left, Type::Condition)?,
+                self.typed(right, Type::Condition)?,
+            );
+            left = Node {
+                expr: Expr::And(Box::new(l), Box::new(r)),
+                ty: Type::Condition,
+                text,
+            };
+        }
+        Ok(left)
+    }
+
+    fn not(&mut self) -> Result<Node<'a>, ParseError> {
+        let here = self.here();
+        if !self.eat("not") {
+            return self.comparison();
+        }
+        let inner = self.not()?;
+        let text = self.span(here, inner.text);
+        Ok(Node {
+            expr: Expr::Not(Box::new(self.typed(inner, Type::Condition)?)),
+            ty: Type::Condition,
+            text,
+        })
+    }
+
+    fn comparison(&mut self) -> Result<Node<'a>, ParseError> {
+        let left = self.sum()?;
+        let op = match self.peek() {
+            Some("<") => Op::Lt,
+            Some("<=") => Op::Le,
+            Some(">") => Op::Gt,
+            Some(">=") => Op::Ge,
+            Some("==") => Op::Eq,
+            Some("!=") => Op::Ne,
+            _ => return Ok(left),
+        };
+        self.pos += 1;
+
+        let right = self.sum()?;
+        let text = self.span(left.text, right.text);
+        let (l, r) = (
+            self.typed(left, Type::Number)?,
+            self.typed(right, Type::Number)?,
+        );
+        Ok(Node {
+            expr: Expr::Binary(Box::new(l), op, Box::new(r)),
+            ty: Type::Condition,
+            text,
+        })
+    }
+
+    fn sum(&mut self) -> Result<Node<'a>, ParseError> {
+        let mut left = self.product()?;
+        loop {
+            let op = match self.peek() {
+                Some("+") => Op::Add,
+                Some("-") => Op::Sub,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            let right = self.product()?;
+            left = self.arithmetic(left, op, right)?;
+        }
+    }
+
+    fn product(&mut self) -> Result<Node<'a>, ParseError> {
+        let mut left = self.primary()?;
+        while self.eat("*") {
+            let right = self.primary()?;
+            left = self.arithmetic(left, Op::Mul, right)?;
+        }
+        Ok(left)
+    }
+
+    fn arithmetic(&self, left: Node<'a>, op: Op, right: Node<'a>) -> Result<Node<'a>, ParseError> {
+        let text = self.span(left.text, right.text);
+        let (l, r) = (
+            self.typed(left, Type::Number)?,
+            self.typed(right, Type::Number)?,
+        );
+        Ok(Node {
+            expr: Expr::Binary(Box::new(l), op, Box::new(r)),
+            ty: Type::Number,
+            text,
+        })
+    }
+
+    fn primary(&mut self) -> Result<Node<'a>, ParseError> {
+        let token = self.here();
+        let Some(first) = token.chars().next() else {
+            return Err(ParseError::new(
+                self.source,
+                token,
+                "a number, field or `(`",
+            ));
+        };
+        self.pos += 1;
+
+        if token == "(" {
+            let inner = self.or()?;
+            let close = self.expect(")")?;
+            return Ok(Node {
+                text: self.span(token, close),
+                ..inner
+            });
+        }
+        if first.is_ascii_digit() {
+            let n = token
+                .parse::<i64>()
+                .map_err(|_| ParseError::new(self.source, token, "a number"))?;
+            return Ok(Node {
+                expr: Expr::Int(n),
+                ty: Type::Number,
+                text: token,
+            });
+        }
+        if token == "true" || token == "false" {
+            return Ok(Node {
+                expr: Expr::Bool(token == "true"),
+                ty: Type::Condition,
+                text: token,
+            });
+        }
+        if token == "any" || token == "all" {
+            if self.in_draw {
+                return Err(ParseError::new(
+                    self.source,
+                    token,
+                    "a field of the draw (any() and all() don't nest)",
+                ));
+            }
+            self.expect("(")?;
+            self.in_draw = true;
+            let inner = self.or()?;
+            self.in_draw = false;
+            let close = self.expect(")")?;
+            return Ok(Node {
+                expr: Expr::Draws(
+                    token == "all",
+                    Box::new(self.typed(inner, Type::Condition)?),
+                ),
+                ty: Type::Condition,
+                text: self.span(token, close),
+            });
+        }
+
+        Ok(Node {
+            expr: Expr::Field(self.field(token)?),
+            ty: Type::Number,
+            text: token,
+        })
+    }
+
+    fn field(&self, token: &'a str) -> Result<Field, ParseError> {
+        let color = |name: &str| self.colors.contains(&name).then(|| name.to_owned());
+        let field = match token.split_once('.') {
+            None if token == "id" => Some(Field::Id),
+            None if token == "power" => Some(Field::Power),
+            None if token == "draws" => Some(Field::Draws),
+            None if token == "cubes" && self.in_draw => Some(Field::Cubes),
+            None if self.in_draw => color(token).map(Field::Count),
+            Some(("max", name)) => color(name).map(Field::Max),
+            Some(("min", name)) => color(name).map(Field::Min),
+            Some(("total", name)) => color(name).map(Field::Total),
+            _ => None,
+        };
+
+        field.ok_or_else(|| {
+            if let Some((_, name)) = token.split_once('.') {
+                return ParseError::new(self.source, name, "a color of the input");
+            }
+            let expected = match self.in_draw {
+                true => "a field of the draw or session",
+                false => "a field of the session (colors of a draw only within any() or all())",
+            };
+            ParseError::new(self.source, token, expected)
+        })
+    }
+}
+
+impl Filter {
+    // Colors are the ones a filter may name, any others being taken for typos
+    pub fn parse(source: &str, colors: &[&str]) -> Result<Self, ParseError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            source,
+            tokens,
+            pos: 0,
+            colors,
+            in_draw: false,
+        };
+
+        let node = parser.or()?;
+        if parser.peek().is_some() {
+            let here = parser.here();
+            return Err(ParseError::new(
+                source,
+                here,
+                "`and`, `or` or the end of the filter",
+            ));
+        }
+        let condition = parser.typed(node, Type::Condition)?;
+
+        Ok(Filter { condition })
+    }
+
+    pub fn matches(&self, session: &Session) -> bool {
+        self.condition.holds(session, None)
+    }
+}
@@ -0,0 +1,364 @@
+use aoc_core::{ParseError, Solution};
+use std::fmt;
+use std::str::FromStr;
+
+mod estimate;
+mod filter;
+mod limits;
+mod stats;
+
+pub use estimate::{ColorEstimate, Estimate, Estimator, Model, Prior};
+pub use filter::Filter;
+pub use limits::{parse_limits_file, Excess, Limits};
+pub use stats::{ColorStats, Stats};
+
+// Cube counts keyed by color, in the order the colors were first seen
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Game {
+    cubes: Vec<(String, u32)>,
+}
+
+impl Game {
+    pub fn build() -> Self {
+        Game { cubes: vec![] }
+    }
+
+    pub fn build_full(cubes: &[(&str, u32)]) -> Self {
+        let mut game = Game::build();
+        for &(color, count) in cubes {
+            game.set(color, count);
+        }
+        game
+    }
+
+    // Colors that were never drawn have no cubes
+    pub fn count(&self, color: &str) -> u32 {
+        self.cubes
+            .iter()
+            .find(|(c, _)| c == color)
+            .map_or(0, |&(_, count)| count)
+    }
+
+    pub fn set(&mut self, color: &str, count: u32) -> &Self {
+        match self.cubes.iter_mut().find(|(c, _)| c == color) {
+            Some(cubes) => cubes.1 = count,
+            None => self.cubes.push((color.to_owned(), count)),
+        }
+        self
+    }
+
+    pub fn cubes(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.cubes
+            .iter()
+            .map(|(color, count)| (color.as_str(), *count))
+    }
+
+    // A color missing from the bag can't be drawn at all
+    pub fn is_possible(&self, max_game: &Self) -> bool {
+        self.cubes()
+            .all(|(color, count)| count <= max_game.count(color))
+    }
+
+    // Product of the counts of every color present, or None if it doesn't fit
+    // in 64 bits
+    pub fn power(&self) -> Option<u64> {
+        self.cubes()
+            .try_fold(1u64, |acc, (_, count)| acc.checked_mul(count as u64))
+    }
+
+    // The same cubes with the colors in a fixed order
+    pub fn normalized(&self) -> Self {
+        let mut cubes = self.cubes.clone();
+        cubes.sort_by(|(a, _), (b, _)| color_rank(a).cmp(&color_rank(b)));
+        Game { cubes }
+    }
+
+    // The bag of the puzzle's first part
+    pub fn puzzle_bag() -> Self {
+        Game::build_full(&[("red", 12), ("green", 13), ("blue", 14)])
+    }
+}
+
+// Written the way a draw is, `3 blue, 4 red`
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cubes: Vec<String> = self
+            .cubes()
+            .map(|(color, count)| format!("{} {}", count, color))
+            .collect();
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+// The colors of the puzzle come first, in its order, then any others
+// alphabetically
+fn color_rank(color: &str) -> (usize, &str) {
+    let known = KNOWN_COLORS.iter().position(|&c| c == color);
+    (known.unwrap_or(KNOWN_COLORS.len()), color)
+}
+
+// Reads a bag written like a draw, tolerating extra spaces. Naming a color
+// twice is an error.
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut game = Game::build();
+        for cubes in s.split(',') {
+            let cubes = cubes.trim();
+            let Some((num_str, color)) = cubes.split_once(' ') else {
+                return Err(ParseError::new(s, &cubes[cubes.len()..], "a cube color"));
+            };
+            let num = num_str
+                .parse::<u32>()
+                .map_err(|_| ParseError::new(s, num_str, "a cube count"))?;
+            let color = color.trim_start();
+            if game.cubes().any(|(c, _)| c == color) {
+                return Err(ParseError::new(s, color, "a color not already in the bag"));
+            }
+            game.set(color, num);
+        }
+        Ok(game)
+    }
+}
+
+// Sum of the ids of the sessions that could have been played with the bag
+pub fn possible_id_sum(sessions: &[Session], bag: &Game) -> u32 {
+    sessions
+        .iter()
+        .filter(|&s| s.is_possible(bag))
+        .fold(0, |acc, s| acc + s.id)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    id: u32,
+    games: Vec<Game>,
+}
+
+impl Session {
+    pub fn build(id: u32) -> Self {
+        Session { id, games: vec![] }
+    }
+
+    pub fn set_games(&mut self, games: Vec<Game>) -> &Self {
+        self.games = games;
+        self
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn games(&self) -> &[Game] {
+        &self.games
+    }
+
+    pub fn normalized(&self) -> Self {
+        let games = self.games.iter().map(Game::normalized).collect();
+        Session { id: self.id, games }
+    }
+
+    pub fn is_possible(&self, max_game: &Game) -> bool {
+        self.games.iter().all(|g| g.is_possible(max_game))
+    }
+
+    // The smallest bag every game of the session could have been drawn from
+    pub fn maximum_possible_game(&self) -> Game {
+        let mut game = Game::build();
+
+        for g in self.games.iter() {
+            for (color, count) in g.cubes() {
+                if count > game.count(color) {
+                    game.set(color, count);
+                }
+            }
+        }
+
+        return game;
+    }
+}
+
+// Colors the puzzle itself uses
+pub const KNOWN_COLORS: [&str; 3] = ["red", "green", "blue"];
+
+// How to treat a draw with an unknown color, or a color named twice
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    // Reject the session
+    Strict,
+    // Keep the cubes, adding up repeated colors, and warn about it
+    Lenient,
+}
+
+impl Session {
+    // Parses a `Game N: ...` line, any warnings being returned alongside the
+    // session. Malformed lines are errors in either mode.
+    pub fn parse(
+        s: &str,
+        colors: &[&str],
+        mode: Mode,
+    ) -> Result<(Session, Vec<ParseError>), ParseError> {
+        let mut warnings: Vec<ParseError> = vec![];
+        let mut report = |issue: ParseError| match mode {
+            Mode::Strict => Err(issue),
+            Mode::Lenient => {
+                warnings.push(issue);
+                Ok(())
+            }
+        };
+
+        let Some(rest) = s.strip_prefix("Game ") else {
+            let word = s.split(' ').next().unwrap();
+            return Err(ParseError::new(s, word, "`Game`"));
+        };
+        let Some((session_id_str, rest)) = rest.split_once(":") else {
+            return Err(ParseError::at_end(s, "`:`"));
+        };
+        let session_id = session_id_str
+            .parse::<u32>()
+            .map_err(|_| ParseError::new(s, session_id_str, "a game number"))?;
+        let mut session = Session::build(session_id);
+
+        let mut games: Vec<Game> = vec![];
+        for game_str in rest.split(";") {
+            let mut game = Game::build();
+            for item in game_str.split(',') {
+                let cubes = item.trim();
+                if cubes.is_empty() {
+                    return Err(ParseError::new(s, &item[item.len()..], "a cube count"));
+                }
+                let leading = &item[..item.len() - item.trim_start().len()];
+                if leading.is_empty() {
+                    let count = cubes.split(' ').next().unwrap();
+                    report(ParseError::new(s, count, "` ` before a cube count"))?;
+                } else if leading != " " {
+                    report(ParseError::new(
+                        s,
+                        &leading[1..],
+                        "a single ` ` before a cube count",
+                    ))?;
+                }
+                let trailing = &item[item.trim_end().len()..];
+                if !trailing.is_empty() {
+                    report(ParseError::new(
+                        s,
+                        trailing,
+                        "`,`, `;` or the end of the line after a cube color",
+                    ))?;
+                }
+                let Some((num_str, spaced_color)) = cubes.split_once(" ") else {
+                    return Err(ParseError::new(s, &cubes[cubes.len()..], "a cube color"));
+                };
+                let color = spaced_color.trim_start();
+                if color.len() != spaced_color.len() {
+                    report(ParseError::new(
+                        s,
+                        &spaced_color[..spaced_color.len() - color.len()],
+                        "a single ` ` before a cube color",
+                    ))?;
+                }
+                let num = num_str
+                    .parse::<u32>()
+                    .map_err(|_| ParseError::new(s, num_str, "a cube count"))?;
+                if color.is_empty() {
+                    return Err(ParseError::new(s, color, "a cube color"));
+                }
+
+                if !colors.contains(&color) {
+                    let expected = format!("one of the colors {}", colors.join(", "));
+                    report(ParseError::new(s, color, &expected))?;
+                }
+                let drawn = game.count(color);
+                if game.cubes().any(|(c, _)| c == color) {
+                    report(ParseError::new(s, color, "a color not already in the draw"))?;
+                }
+                game.set(color, drawn + num);
+            }
+            games.push(game);
+        }
+        session.set_games(games);
+
+        return Ok((session, warnings));
+    }
+}
+
+// Parses every session of the input, with errors and warnings positioned
+// within the whole input
+pub fn parse_sessions(
+    input: &str,
+    colors: &[&str],
+    mode: Mode,
+) -> Result<(Vec<Session>, Vec<ParseError>), ParseError> {
+    let mut sessions: Vec<Session> = vec![];
+    let mut warnings: Vec<ParseError> = vec![];
+
+    for line in aoc_core::lines(input) {
+        let (session, line_warnings) =
+            Session::parse(line, colors, mode).map_err(|e| e.in_context(input, line))?;
+        sessions.push(session);
+        warnings.extend(line_warnings.into_iter().map(|w| w.in_context(input, line)));
+    }
+
+    Ok((sessions, warnings))
+}
+
+// Writes the session back out as a line of the puzzle input
+impl fmt::Display for Session {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let games: Vec<String> = self.games.iter().map(|g| g.to_string()).collect();
+        write!(f, "Game {}: {}", self.id, games.join("; "))
+    }
+}
+
+// Rewrites hand-edited input the canonical way: single spaces, repeated
+// colors of a draw added up and colors in a fixed order. Unknown colors are
+// kept.
+pub fn normalize(input: &str) -> Result<String, ParseError> {
+    let (sessions, _) = parse_sessions(input, &KNOWN_COLORS, Mode::Lenient)?;
+    Ok(sessions
+        .iter()
+        .map(|s| format!("{}\n", s.normalized()))
+        .collect())
+}
+
+// Accepts any color, as the lenient mode does, without the warnings
+impl FromStr for Session {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Session::parse(s, &KNOWN_COLORS, Mode::Lenient).map(|(session, _)| session)
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Session>;
+    type Answer1 = u32;
+    type Answer2 = Result<u64, String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc_core::parse_lines(input)
+    }
+
+    // Sum of possible games
+    fn part_1(sessions: &Self::Input) -> u32 {
+        possible_id_sum(sessions, &Game::puzzle_bag())
+    }
+
+    // Sum of game powers
+    fn part_2(sessions: &Self::Input) -> Result<u64, String> {
+        sessions.iter().try_fold(0u64, |acc, s| {
+            let power = s
+                .maximum_possible_game()
+                .power()
+                .ok_or_else(|| format!("the power of Game {} doesn't fit in 64 bits", s.id))?;
+            acc.checked_add(power)
+                .ok_or_else(|| "the sum of the powers doesn't fit in 64 bits".to_owned())
+        })
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{Game, Session};
+use aoc_core::ParseError;
+use std::fmt;
+use std::str::FromStr;
+
+// A named bag to check sessions against
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Limits {
+    pub name: String,
+    pub bag: Game,
+}
+
+// `<name>: <count> <color>, ...`, or just the bag, which then names itself
+impl FromStr for Limits {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, bag_str) = match s.split_once(':') {
+            Some((name, bag_str)) => (name.trim(), bag_str),
+            None => (s.trim(), s),
+        };
+        let bag = bag_str
+            .parse::<Game>()
+            .map_err(|e| e.in_context(s, bag_str))?;
+
+        Ok(Limits {
+            name: name.to_owned(),
+            bag,
+        })
+    }
+}
+
+// One set of limits per line, # starts a comment
+pub fn parse_limits_file(text: &str) -> Result<Vec<Limits>, ParseError> {
+    text.lines()
+        .map(|line| line.split('#').next().unwrap())
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.parse::<Limits>().map_err(|e| e.in_context(text, line)))
+        .collect()
+}
+
+// A color of a draw with more cubes than the bag holds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Excess {
+    pub session: u32,
+    // 1-based, in the order of the session line
+    pub draw: usize,
+    pub color: String,
+    pub count: u32,
+    pub limit: u32,
+}
+
+impl fmt::Display for Excess {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Game {}: draw {} has {} {}, over the limit of {}",
+            self.session, self.draw, self.count, self.color, self.limit
+        )
+    }
+}
+
+impl Session {
+    // Every draw and color that makes the session impossible with the bag
+    pub fn excesses(&self, bag: &Game) -> Vec<Excess> {
+        self.games
+            .iter()
+            .enumerate()
+            .flat_map(|(i, game)| {
+                game.cubes()
+                    .filter(|&(color, count)| count > bag.count(color))
+                    .map(move |(color, count)| Excess {
+                        session: self.id,
+                        draw: i + 1,
+                        color: color.to_owned(),
+                        count,
+                        limit: bag.count(color),
+                    })
+            })
+            .collect()
+    }
+}
@@ -1,153 +1,331 @@
+use aoc_core::cli::InputArgs;
+use aoc_core::input;
+use aoc_core::runner::Part;
+use aoc_core::table;
+use aoc_core::Solution;
+use clap::{ArgGroup, Parser};
+use day_2::{Day2, Estimator, Filter, Limits, Mode, Model, Prior, Session, Stats, KNOWN_COLORS};
 use std::fs;
-use std::str::FromStr;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
-#[derive(Debug, Copy, Clone)]
-struct Game {
-    red: u32,
-    green: u32,
-    blue: u32,
+#[derive(Parser)]
+#[command(group(ArgGroup::new("report").args(["stats", "estimate"])))]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Reject cube colors other than red, green and blue, and colors named
+    /// twice in a draw, rather than warning about them
+    #[arg(long)]
+    strict: bool,
+
+    /// Check the sessions against this bag, such as "12 red, 13 green, 14 blue"
+    /// or "name: 12 red, ...", instead of solving. Can be repeated.
+    #[arg(long, value_name = "BAG")]
+    limits: Vec<String>,
+
+    /// Check the sessions against every bag of this file, one per line
+    #[arg(long, value_name = "PATH")]
+    limits_file: Option<PathBuf>,
+
+    /// Print statistics of the cubes drawn instead of solving
+    #[arg(long, conflicts_with_all = ["limits", "limits_file"])]
+    stats: bool,
+
+    /// Estimate the bag of each session from its draws instead of solving
+    #[arg(long, conflicts_with_all = ["limits", "limits_file", "stats"])]
+    estimate: bool,
+
+    /// Prior of the estimate, the same for every color: uniform[:MAX] or
+    /// poisson:MEAN[:MAX]
+    #[arg(long, requires = "estimate", default_value = "uniform:25")]
+    prior: Prior,
+
+    /// How the estimate takes a draw's cubes out of the bag: multinomial, each
+    /// cube put back before the next, or hypergeometric, all at once
+    #[arg(long, requires = "estimate", default_value = "multinomial")]
+    model: Model,
+
+    /// Probability mass of the estimate's credible intervals
+    #[arg(long, requires = "estimate", default_value_t = 0.9)]
+    level: f64,
+
+    /// Print statistics or estimates as JSON rather than tables
+    #[arg(long, requires = "report")]
+    json: bool,
+
+    /// Print the input rewritten canonically instead of solving
+    #[arg(long, conflicts_with_all = ["strict", "limits", "limits_file", "stats", "estimate"])]
+    normalize: bool,
+
+    /// Print the sessions meeting a condition, such as
+    /// "any(green > 10) and power > 500", and their id and power sums
+    #[arg(
+        long,
+        value_name = "EXPR",
+        conflicts_with_all = ["limits", "limits_file", "report", "normalize"]
+    )]
+    filter: Option<String>,
 }
 
-impl Game {
-    fn build() -> Self {
-        Game {
-            red: 0,
-            green: 0,
-            blue: 0,
+impl Args {
+    fn mode(&self) -> Mode {
+        if self.strict {
+            return Mode::Strict;
         }
+        Mode::Lenient
     }
 
-    fn build_full(red: u32, green: u32, blue: u32) -> Self {
-        Game { red, green, blue }
-    }
+    fn limits(&self) -> Result<Vec<Limits>, String> {
+        let mut sets: Vec<Limits> = vec![];
+        for arg in self.limits.iter() {
+            let limits = arg
+                .parse::<Limits>()
+                .map_err(|e| e.diagnostic(arg, "--limits"))?;
+            sets.push(limits);
+        }
 
-    fn red(&mut self, val: u32) -> &Self {
-        self.red = val;
-        self
-    }
+        if let Some(path) = &self.limits_file {
+            let text = fs::read_to_string(path)
+                .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+            let file_sets = day_2::parse_limits_file(&text)
+                .map_err(|e| e.diagnostic(&text, &path.display().to_string()))?;
+            sets.extend(file_sets);
+        }
 
-    fn green(&mut self, val: u32) -> &Self {
-        self.green = val;
-        self
+        Ok(sets)
     }
+}
 
-    fn blue(&mut self, val: u32) -> &Self {
-        self.blue = val;
-        self
-    }
+// Sessions parsed from an input, with the parts it is the input of
+type Parsed = (Vec<Part>, Vec<Session>);
 
-    fn is_possible(&self, max_game: &Self) -> bool {
-        self.red <= max_game.red && self.green <= max_game.green && self.blue <= max_game.blue
+// Parses the input of each of the parts, printing any warnings
+fn load(args: &Args, parts: &[Part]) -> Result<Vec<Parsed>, String> {
+    let mut inputs: Vec<Parsed> = vec![];
+
+    for loaded in input::load(&args.input.source(), Path::new(Day2::DIR), parts)? {
+        let (sessions, warnings) = day_2::parse_sessions(&loaded.text, &KNOWN_COLORS, args.mode())
+            .map_err(|e| e.diagnostic(&loaded.text, &loaded.origin))?;
+        for warning in warnings.iter() {
+            eprintln!("{}\n", warning.warning(&loaded.text, &loaded.origin));
+        }
+        inputs.push((loaded.parts, sessions));
     }
+
+    Ok(inputs)
 }
 
-#[derive(Debug, Clone)]
-struct Session {
-    id: u32,
-    games: Vec<Game>,
+// A part's answer, or why it has none
+type Answer = (Part, Result<String, String>);
+
+fn solve(args: &Args) -> Result<Vec<Answer>, String> {
+    let mut answers: Vec<Answer> = vec![];
+    for (parts, sessions) in load(args, Part::BOTH)? {
+        for part in parts {
+            let answer = match part {
+                Part::One => Ok(Day2::part_1(&sessions).to_string()),
+                Part::Two => Day2::part_2(&sessions).map(|sum| sum.to_string()),
+            };
+            answers.push((part, answer));
+        }
+    }
+    answers.sort();
+
+    Ok(answers)
 }
 
-impl Session {
-    fn build(id: u32) -> Self {
-        Session {
-            id: id,
-            games: vec![],
+// Prints, for each bag, the possible sessions' id sum and what rules out the
+// others
+fn check_limits(args: &Args, sets: &[Limits]) -> Result<(), String> {
+    let (_, sessions) = load(args, &[Part::One])?.remove(0);
+
+    for (i, limits) in sets.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let mut label = limits.name.clone();
+        if label != limits.bag.to_string() {
+            label = format!("{} ({})", label, limits.bag);
+        }
+        println!(
+            "{}: {}",
+            label,
+            day_2::possible_id_sum(&sessions, &limits.bag)
+        );
+        for excess in sessions.iter().flat_map(|s| s.excesses(&limits.bag)) {
+            println!("  {}", excess);
         }
     }
 
-    fn set_games(&mut self, games: Vec<Game>) -> &Self {
-        self.games = games;
-        self
+    Ok(())
+}
+
+fn print_stats(args: &Args) -> Result<(), String> {
+    let (_, sessions) = load(args, &[Part::One])?.remove(0);
+    let stats = Stats::build(&sessions);
+    if args.json {
+        println!("{}", stats.to_json());
+    } else {
+        println!("{}", stats.to_table());
     }
 
-    fn is_possible(&self, max_game: &Game) -> bool {
-        !self.games.iter().any(|&g| !g.is_possible(max_game))
+    Ok(())
+}
+
+fn print_estimates(args: &Args) -> Result<(), String> {
+    if !(0.0..1.0).contains(&args.level) {
+        return Err(format!("--level must be below 1, not {}", args.level));
     }
+    let (_, sessions) = load(args, &[Part::One])?.remove(0);
+    let stats = Stats::build(&sessions);
+    let colors: Vec<&str> = stats.colors.iter().map(|c| c.color.as_str()).collect();
+    let estimator = Estimator::build(args.prior, args.model, &colors, args.level);
 
-    fn maximum_possible_game(&self) -> Game {
-        let mut game = Game::build();
+    let mut rows: Vec<Vec<String>> = vec![];
+    for session in sessions.iter() {
+        let estimate = estimator.estimate(session)?;
+        if args.json {
+            println!("{}", estimate.to_json());
+            continue;
+        }
 
-        for &g in self.games.iter() {
-            if g.red > game.red {
-                game.red(g.red);
-            }
-            if g.green > game.green {
-                game.green(g.green);
-            }
-            if g.blue > game.blue {
-                game.blue(g.blue);
-            }
+        let mut row = vec![estimate.session.to_string(), estimate.map_bag.to_string()];
+        for c in estimate.colors.iter() {
+            row.push(format!(
+                "{}..{} ({:.1})",
+                c.interval.0, c.interval.1, c.mean
+            ));
         }
+        rows.push(row);
+    }
 
-        return game;
+    if !args.json {
+        let mut headers = vec!["Game".to_owned(), "Most likely bag".to_owned()];
+        for color in colors.iter() {
+            headers.push(format!("{} {}%", color, args.level * 100.0));
+        }
+        let headers: Vec<&str> = headers.iter().map(|h| h.as_str()).collect();
+        println!("Prior {}, {} draws\n", args.prior, args.model);
+        println!("{}", table::render(&headers, &rows));
     }
+
+    Ok(())
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct ParseSessionError;
-
-impl FromStr for Session {
-    type Err = ParseSessionError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (session_id_str, rest) = s
-            .strip_prefix("Game ")
-            .and_then(|s| s.split_once(":"))
-            .unwrap();
-        let session_id = session_id_str
-            .parse::<u32>()
-            .map_err(|_| ParseSessionError)?;
-        let mut session = Session::build(session_id);
-
-        let games = rest
-            .split(";")
-            .map(|s| s.split(',').map(|s| s.strip_prefix(" ")))
-            .map(|game_iter| {
-                let mut game = Game::build();
-                for s in game_iter {
-                    let split: Vec<&str> = s.expect("must be a string").split(" ").collect();
-                    let num = split[0].parse::<u32>().unwrap();
-                    let color = split[1];
-
-                    game = match color {
-                        "red" => *game.red(num),
-                        "green" => *game.green(num),
-                        "blue" => *game.blue(num),
-                        _ => game,
-                    };
-                }
-                return game;
-            })
-            .collect();
-        session.set_games(games);
+fn print_filtered(args: &Args, filter: &str) -> Result<(), String> {
+    let (_, sessions) = load(args, &[Part::One])?.remove(0);
+    // Colors of the input are known too, so that only typos are rejected
+    let stats = Stats::build(&sessions);
+    let mut colors: Vec<&str> = KNOWN_COLORS.to_vec();
+    colors.extend(stats.colors.iter().map(|c| c.color.as_str()));
+    let filter = Filter::parse(filter, &colors).map_err(|e| e.diagnostic(filter, "--filter"))?;
 
-        return Ok(session);
+    let matching: Vec<Session> = sessions
+        .iter()
+        .filter(|s| filter.matches(s))
+        .cloned()
+        .collect();
+    for session in matching.iter() {
+        println!("{}", session);
+    }
+    let id_sum: u32 = matching.iter().map(|s| s.id()).sum();
+    let power_sum = match Day2::part_2(&matching) {
+        Ok(sum) => sum.to_string(),
+        Err(_) => "too large for 64 bits".to_owned(),
+    };
+    if !matching.is_empty() {
+        println!();
     }
+    println!(
+        "{} of {} sessions, id sum {}, power sum {}",
+        matching.len(),
+        sessions.len(),
+        id_sum,
+        power_sum
+    );
+
+    Ok(())
 }
 
-fn main() {
-    let maximum_game: Game = Game::build_full(12, 13, 14);
-    let input = fs::read_to_string("input.txt").expect("failed to open input file");
+fn normalize(args: &Args) -> Result<String, String> {
+    let loaded = input::load(&args.input.source(), Path::new(Day2::DIR), &[Part::One])?.remove(0);
+    day_2::normalize(&loaded.text).map_err(|e| e.diagnostic(&loaded.text, &loaded.origin))
+}
 
-    let sessions: Vec<Session> = input
-        .split("\n")
-        .filter(|&s| !s.is_empty())
-        .map(|line| line.parse::<Session>().unwrap())
-        .collect();
+fn main() -> ExitCode {
+    let args = Args::parse();
+    if args.normalize {
+        return match normalize(&args) {
+            Ok(text) => {
+                print!("{}", text);
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
 
-    // Part 1 - Sum of possible games
-    let sum = sessions
-        .iter()
-        .filter(|&s| s.is_possible(&maximum_game))
-        .fold(0, |acc, s| acc + s.id);
+    let sets = match args.limits() {
+        Ok(sets) => sets,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
 
-    println!("{:?}", sum);
+    if let Some(filter) = &args.filter {
+        if let Err(e) = print_filtered(&args, filter) {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
 
-    // Part 2 - Sum of game powers
-    let sum = sessions
-        .iter()
-        .map(|s| s.maximum_possible_game())
-        .fold(0, |acc, g| acc + (g.red * g.green * g.blue));
+    if args.estimate {
+        if let Err(e) = print_estimates(&args) {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if args.stats {
+        if let Err(e) = print_stats(&args) {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
 
-    println!("{:?}", sum);
+    if !sets.is_empty() {
+        if let Err(e) = check_limits(&args, &sets) {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    match solve(&args) {
+        Ok(answers) => {
+            let mut code = ExitCode::SUCCESS;
+            for (part, answer) in answers {
+                match answer {
+                    Ok(answer) => println!("{}", answer),
+                    Err(e) => {
+                        eprintln!("part {}: {}", part, e);
+                        code = ExitCode::FAILURE;
+                    }
+                }
+            }
+            code
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
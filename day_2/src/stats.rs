@@ -0,0 +1,154 @@
+use crate::{Game, Session};
+use aoc_core::table;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+// How one color was drawn over every session
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorStats {
+    pub color: String,
+    // Number of draws showing the color
+    pub draws: usize,
+    // Number of draws for each count of the color drawn
+    pub distribution: BTreeMap<u32, usize>,
+    // Mean count over the draws showing the color
+    pub mean: f64,
+    pub max: u32,
+    // Sessions needing every cube of the color in the global minimum bag
+    pub tight_sessions: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub sessions: usize,
+    // The smallest bag making every session possible
+    pub min_bag: Game,
+    pub colors: Vec<ColorStats>,
+}
+
+impl Stats {
+    pub fn build(sessions: &[Session]) -> Self {
+        let mut min_bag = Game::build();
+        for session in sessions.iter() {
+            for (color, count) in session.maximum_possible_game().cubes() {
+                if count > min_bag.count(color) {
+                    min_bag.set(color, count);
+                }
+            }
+        }
+        let min_bag = min_bag.normalized();
+
+        let colors = min_bag
+            .cubes()
+            .map(|(color, limit)| {
+                let mut distribution: BTreeMap<u32, usize> = BTreeMap::new();
+                for game in sessions.iter().flat_map(|s| s.games.iter()) {
+                    if game.cubes().any(|(c, _)| c == color) {
+                        *distribution.entry(game.count(color)).or_default() += 1;
+                    }
+                }
+                let draws: usize = distribution.values().sum();
+                let total: u64 = distribution
+                    .iter()
+                    .map(|(&n, &d)| n as u64 * d as u64)
+                    .sum();
+
+                ColorStats {
+                    color: color.to_owned(),
+                    draws,
+                    mean: total as f64 / draws.max(1) as f64,
+                    max: limit,
+                    distribution,
+                    tight_sessions: sessions
+                        .iter()
+                        .filter(|s| s.maximum_possible_game().count(color) == limit)
+                        .map(|s| s.id)
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Stats {
+            sessions: sessions.len(),
+            min_bag,
+            colors,
+        }
+    }
+
+    // A summary row per color, then how often each count was drawn
+    pub fn to_table(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .colors
+            .iter()
+            .map(|c| {
+                let tight: Vec<String> = c.tight_sessions.iter().map(|id| id.to_string()).collect();
+                vec![
+                    c.color.clone(),
+                    c.draws.to_string(),
+                    format!("{:.2}", c.mean),
+                    c.max.to_string(),
+                    tight.join(", "),
+                ]
+            })
+            .collect();
+        let summary = table::render(&["Color", "Draws", "Mean", "Max", "Tight sessions"], &rows);
+
+        let mut headers = vec!["Drawn"];
+        headers.extend(self.colors.iter().map(|c| c.color.as_str()));
+        let drawn: BTreeSet<u32> = self
+            .colors
+            .iter()
+            .flat_map(|c| c.distribution.keys().copied())
+            .collect();
+        let rows: Vec<Vec<String>> = drawn
+            .into_iter()
+            .map(|n| {
+                let mut row = vec![n.to_string()];
+                for c in self.colors.iter() {
+                    let draws = c.distribution.get(&n).copied().unwrap_or(0);
+                    row.push(draws.to_string());
+                }
+                row
+            })
+            .collect();
+        let distribution = table::render(&headers, &rows);
+
+        format!(
+            "{} sessions, minimum bag {}\n\n{}\n\n{}",
+            self.sessions, self.min_bag, summary, distribution
+        )
+    }
+
+    pub fn to_json(&self) -> Value {
+        let min_bag: serde_json::Map<String, Value> = self
+            .min_bag
+            .cubes()
+            .map(|(color, count)| (color.to_owned(), json!(count)))
+            .collect();
+        let colors: Vec<Value> = self
+            .colors
+            .iter()
+            .map(|c| {
+                let distribution: Vec<Value> = c
+                    .distribution
+                    .iter()
+                    .map(|(n, draws)| json!({"drawn": n, "draws": draws}))
+                    .collect();
+                json!({
+                    "color": c.color,
+                    "draws": c.draws,
+                    "mean": c.mean,
+                    "max": c.max,
+                    "distribution": distribution,
+                    "tight_sessions": c.tight_sessions,
+                })
+            })
+            .collect();
+
+        json!({
+            "sessions": self.sessions,
+            "min_bag": min_bag,
+            "colors": colors,
+        })
+    }
+}
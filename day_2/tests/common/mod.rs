@@ -0,0 +1,10 @@
+use aoc_core::Solution;
+use day_2::{Day2, Session};
+use std::fs;
+use std::path::Path;
+
+// The sessions of the puzzle's example
+pub fn sample() -> Vec<Session> {
+    let text = fs::read_to_string(Path::new(Day2::DIR).join("sample_input.txt")).unwrap();
+    Day2::parse(&text).unwrap()
+}
@@ -0,0 +1,132 @@
+use day_2::{Estimator, Model, Prior, Session};
+
+fn session(line: &str) -> Session {
+    line.parse().unwrap()
+}
+
+#[test]
+fn reads_priors() {
+    assert_eq!("uniform".parse(), Ok(Prior::Uniform { max: 25 }));
+    assert_eq!("uniform:12".parse(), Ok(Prior::Uniform { max: 12 }));
+    assert_eq!(
+        "poisson:8:30".parse(),
+        Ok(Prior::Poisson { mean: 8.0, max: 30 })
+    );
+    assert!("poisson".parse::<Prior>().is_err());
+    assert!("poisson:-1".parse::<Prior>().is_err());
+    assert!("poisson:inf".parse::<Prior>().is_err());
+    assert!("poisson:NaN".parse::<Prior>().is_err());
+    assert_eq!(
+        "uniform:4294967295".parse::<Prior>(),
+        Err("`4294967295` is too many cubes, a prior allows at most 200".to_owned())
+    );
+    assert!("beta:1:2".parse::<Prior>().is_err());
+}
+
+#[test]
+fn colors_never_drawn_are_most_likely_absent() {
+    let estimator = Estimator::build(
+        Prior::Uniform { max: 10 },
+        Model::Hypergeometric,
+        &["red", "blue"],
+        0.9,
+    );
+    let estimate = estimator
+        .estimate(&session("Game 7: 2 red; 2 red; 2 red"))
+        .unwrap();
+
+    assert_eq!(estimate.session, 7);
+    assert_eq!(estimate.map_bag.to_string(), "2 red, 0 blue");
+    let (red, blue) = (&estimate.colors[0], &estimate.colors[1]);
+    assert!(red.interval.0 >= 2);
+    assert_eq!(blue.interval.0, 0);
+    assert!(blue.mean < red.mean);
+}
+
+#[test]
+fn even_draws_point_to_an_even_bag() {
+    let estimator = Estimator::build(
+        Prior::Poisson { mean: 5.0, max: 15 },
+        Model::Multinomial,
+        &["red", "blue"],
+        0.5,
+    );
+    let estimate = estimator
+        .estimate(&session(
+            "Game 1: 3 red, 3 blue; 2 blue, 2 red; 4 red, 4 blue",
+        ))
+        .unwrap();
+
+    let (red, blue) = (&estimate.colors[0], &estimate.colors[1]);
+    assert_eq!(
+        estimate.map_bag.count("red"),
+        estimate.map_bag.count("blue")
+    );
+    assert_eq!(red.interval, blue.interval);
+    assert!((red.mean - blue.mean).abs() < 1e-9);
+    assert!(red.interval.0 >= 4 && red.interval.0 <= red.interval.1);
+}
+
+#[test]
+fn reads_models() {
+    assert_eq!("multinomial".parse(), Ok(Model::Multinomial));
+    assert_eq!("hypergeometric".parse(), Ok(Model::Hypergeometric));
+    assert!("binomial".parse::<Model>().is_err());
+}
+
+#[test]
+fn multinomial_posterior_matches_a_direct_sum() {
+    let estimator = Estimator::build(
+        Prior::Uniform { max: 4 },
+        Model::Multinomial,
+        &["red", "blue"],
+        0.9,
+    );
+    let estimate = estimator
+        .estimate(&session("Game 1: 2 red, 1 blue; 1 red"))
+        .unwrap();
+
+    // Each cube is red with probability r / (r + b), so the draws have a
+    // likelihood of (r / t)^3 (b / t) up to a constant
+    let (mut mass, mut red, mut blue) = (0.0, 0.0, 0.0);
+    for r in 1..=4 {
+        for b in 1..=4 {
+            let t = (r + b) as f64;
+            let likelihood = (r as f64 / t).powi(3) * (b as f64 / t);
+            mass += likelihood;
+            red += r as f64 * likelihood;
+            blue += b as f64 * likelihood;
+        }
+    }
+    assert!((estimate.colors[0].mean - red / mass).abs() < 1e-9);
+    assert!((estimate.colors[1].mean - blue / mass).abs() < 1e-9);
+    assert_eq!(estimate.map_bag.to_string(), "3 red, 1 blue");
+}
+
+#[test]
+fn only_hypergeometric_draws_need_the_cubes_in_the_bag() {
+    let line = "Game 1: 3 red; 3 blue";
+    let build = |model| Estimator::build(Prior::Uniform { max: 10 }, model, &["red", "blue"], 0.9);
+
+    let estimate = build(Model::Multinomial).estimate(&session(line)).unwrap();
+    assert_eq!(estimate.map_bag.to_string(), "1 red, 1 blue");
+    assert!(estimate.colors[0].interval.0 < 3);
+
+    let estimate = build(Model::Hypergeometric)
+        .estimate(&session(line))
+        .unwrap();
+    assert!(estimate.map_bag.count("red") >= 3);
+    assert!(estimate.colors[0].interval.0 >= 3);
+}
+
+#[test]
+fn sessions_the_prior_cannot_explain_are_errors() {
+    let build = |model| Estimator::build(Prior::Uniform { max: 5 }, model, &["red", "blue"], 0.9);
+    let estimator = build(Model::Hypergeometric);
+    assert!(estimator.estimate(&session("Game 1: 6 red")).is_err());
+    assert!(estimator.estimate(&session("Game 2: 1 teal")).is_err());
+    // One red cube put back each time can be drawn six times over
+    assert!(build(Model::Multinomial)
+        .estimate(&session("Game 1: 6 red"))
+        .is_ok());
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use common::sample;
+use day_2::{Filter, Session, KNOWN_COLORS};
+
+fn matching_ids(filter: &str) -> Vec<u32> {
+    let filter = Filter::parse(filter, &KNOWN_COLORS).unwrap();
+    sample()
+        .iter()
+        .filter(|s| filter.matches(s))
+        .map(Session::id)
+        .collect()
+}
+
+#[test]
+fn draws_are_quantified_over() {
+    assert_eq!(matching_ids("any(green > 10) and power > 500"), [3]);
+    assert_eq!(
+        matching_ids("all(red <= 12 and green <= 13 and blue <= 14)"),
+        [1, 2, 5]
+    );
+    assert_eq!(matching_ids("not any(cubes < 5)"), [3, 4, 5]);
+}
+
+#[test]
+fn session_fields_combine_with_arithmetic() {
+    assert_eq!(matching_ids("max.red * 2 + 1 == 13"), [5]);
+    // A draw without a color counts as none of it
+    assert_eq!(matching_ids("total.green - min.green == 4"), [1]);
+    assert_eq!(matching_ids("draws == 2 or power < 40"), [2, 5]);
+}
+
+#[test]
+fn and_binds_tighter_than_or() {
+    assert_eq!(matching_ids("id == 1 or id == 2 and power > 100"), [1]);
+    assert_eq!(
+        matching_ids("(id == 1 or id == 2) and power > 100"),
+        Vec::<u32>::new()
+    );
+}
+
+#[test]
+fn comparisons_that_overflow_are_false() {
+    assert!(matching_ids("id * 9223372036854775807 * 2 > 0").is_empty());
+    assert!(matching_ids("0 - 9223372036854775807 - id - 1 < 0").is_empty());
+    assert_eq!(
+        matching_ids("not (id * 9223372036854775807 * 2 > 0)"),
+        [1, 2, 3, 4, 5]
+    );
+}
+
+#[test]
+fn errors_point_into_the_filter() {
+    let error = Filter::parse("red > 3", &KNOWN_COLORS).unwrap_err();
+    assert_eq!((error.column, error.found.as_str()), (1, "red"));
+
+    let error = Filter::parse("any(green > 1) and max.gren > 1", &KNOWN_COLORS).unwrap_err();
+    assert_eq!((error.column, error.found.as_str()), (24, "gren"));
+
+    let error = Filter::parse("power and id > 1", &KNOWN_COLORS).unwrap_err();
+    assert_eq!((error.column, error.expected.as_str()), (1, "a condition"));
+
+    let error = Filter::parse("any(any(red > 1))", &KNOWN_COLORS).unwrap_err();
+    assert_eq!((error.column, error.found.as_str()), (5, "any"));
+}
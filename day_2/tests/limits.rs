@@ -0,0 +1,43 @@
+use day_2::{Excess, Game, Limits, Session};
+
+#[test]
+fn lists_every_draw_and_color_over_the_limit() {
+    let session: Session = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green"
+        .parse()
+        .unwrap();
+    let bag: Game = "12 red, 13 green, 5 blue".parse().unwrap();
+
+    let excess = |draw, color: &str, count, limit| Excess {
+        session: 3,
+        draw,
+        color: color.to_owned(),
+        count,
+        limit,
+    };
+    assert_eq!(
+        session.excesses(&bag),
+        [excess(1, "blue", 6, 5), excess(1, "red", 20, 12)]
+    );
+    assert!(!session.is_possible(&bag));
+}
+
+#[test]
+fn reads_named_and_unnamed_limit_sets() {
+    let text = "# bags\npuzzle: 12 red, 13 green, 14 blue\n\n 1 red,2 teal \n";
+    let sets = day_2::parse_limits_file(text).unwrap();
+
+    assert_eq!(sets[0].name, "puzzle");
+    assert_eq!(sets[0].bag, Game::puzzle_bag());
+    assert_eq!(sets[1].name, "1 red,2 teal");
+    assert_eq!(sets[1].bag.to_string(), "1 red, 2 teal");
+}
+
+#[test]
+fn limit_errors_point_into_the_file() {
+    let text = "puzzle: 12 red, 13 green, 14 blue\nsmall: 5 red, x green\n";
+    let error = day_2::parse_limits_file(text).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 15));
+
+    let error = "big: 9 red, 9 red".parse::<Limits>().unwrap_err();
+    assert_eq!((error.column, error.found.as_str()), (15, "red"));
+}
@@ -0,0 +1,91 @@
+use day_2::{Mode, Session, KNOWN_COLORS};
+
+#[test]
+fn strict_mode_rejects_unknown_and_repeated_colors() {
+    let line = "Game 4: 1 red, 2 teal; 3 blue";
+    let error = Session::parse(line, &KNOWN_COLORS, Mode::Strict).unwrap_err();
+    assert_eq!((error.column, error.found.as_str()), (18, "teal"));
+
+    let line = "Game 5: 1 red, 2 green, 3 red";
+    let error = Session::parse(line, &KNOWN_COLORS, Mode::Strict).unwrap_err();
+    assert_eq!((error.column, error.found.as_str()), (27, "red"));
+    assert_eq!(error.expected, "a color not already in the draw");
+}
+
+#[test]
+fn lenient_mode_keeps_the_cubes_and_warns() {
+    let line = "Game 5: 1 red, 2 teal, 3 red";
+    let (session, warnings) = Session::parse(line, &KNOWN_COLORS, Mode::Lenient).unwrap();
+
+    let game = &session.games()[0];
+    assert_eq!((game.count("red"), game.count("teal")), (4, 2));
+    let found: Vec<&str> = warnings.iter().map(|w| w.found.as_str()).collect();
+    assert_eq!(found, ["teal", "red"]);
+}
+
+#[test]
+fn stray_spaces_are_pointed_at_alone() {
+    let warned = |line: &str| -> Vec<(usize, String, String)> {
+        let (_, warnings) = Session::parse(line, &KNOWN_COLORS, Mode::Lenient).unwrap();
+        warnings
+            .into_iter()
+            .map(|w| (w.column, w.found, w.expected))
+            .collect()
+    };
+
+    assert_eq!(
+        warned("Game 1: 3 blue "),
+        [(
+            15,
+            " ".to_owned(),
+            "`,`, `;` or the end of the line after a cube color".to_owned()
+        )]
+    );
+    assert_eq!(
+        warned("Game 1:  3 blue,4 red"),
+        [
+            (
+                9,
+                " ".to_owned(),
+                "a single ` ` before a cube count".to_owned()
+            ),
+            (17, "4".to_owned(), "` ` before a cube count".to_owned()),
+        ]
+    );
+    assert_eq!(
+        warned("Game 1: 3  blue"),
+        [(
+            11,
+            " ".to_owned(),
+            "a single ` ` before a cube color".to_owned()
+        )]
+    );
+    assert!(warned("Game 1: 3 blue, 4 red; 1 green").is_empty());
+}
+
+#[test]
+fn malformed_lines_fail_in_either_mode() {
+    for mode in [Mode::Strict, Mode::Lenient] {
+        let error = Session::parse("Game 1 3 red", &KNOWN_COLORS, mode).unwrap_err();
+        assert_eq!(error.expected, "`:`");
+
+        let error = Session::parse("Game 1: x red", &KNOWN_COLORS, mode).unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (9, "a cube count"));
+    }
+}
+
+#[test]
+fn warnings_are_positioned_in_the_whole_input() {
+    let input = "Game 1: 1 red\nGame 2: 2 teal\n";
+    let (sessions, warnings) = day_2::parse_sessions(input, &KNOWN_COLORS, Mode::Lenient).unwrap();
+
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(
+        warnings[0].warning(input, "input.txt"),
+        "warning: expected one of the colors red, green, blue, found `teal`\n \
+         --> input.txt:2:11\n  \
+         |\n\
+         2 | Game 2: 2 teal\n  \
+         |           ^^^^"
+    );
+}
@@ -0,0 +1,23 @@
+use aoc_core::Solution;
+use day_2::{Day2, Game};
+
+#[test]
+fn powers_past_32_bits_are_exact() {
+    let game = Game::build_full(&[("red", 70000), ("blue", 70000)]);
+    assert_eq!(game.power(), Some(4_900_000_000));
+
+    let sessions = Day2::parse("Game 1: 70000 red, 70000 blue\nGame 2: 2 red\n").unwrap();
+    assert_eq!(Day2::part_2(&sessions), Ok(4_900_000_002));
+}
+
+#[test]
+fn powers_past_64_bits_are_an_error() {
+    let game = Game::build_full(&[("red", u32::MAX), ("blue", u32::MAX), ("teal", 2)]);
+    assert_eq!(game.power(), None);
+
+    let sessions = Day2::parse("Game 7: 4294967295 red, 4294967295 blue, 2 teal\n").unwrap();
+    assert_eq!(
+        Day2::part_2(&sessions),
+        Err("the power of Game 7 doesn't fit in 64 bits".to_owned())
+    );
+}
@@ -0,0 +1,62 @@
+use day_2::{Game, Session};
+use proptest::collection::{btree_map, vec};
+use proptest::prelude::*;
+
+const COLORS: [&str; 5] = ["red", "green", "blue", "teal", "yellow"];
+
+fn game() -> impl Strategy<Value = Game> {
+    (
+        btree_map(0..COLORS.len(), 0..30u32, 1..=COLORS.len()),
+        any::<bool>(),
+    )
+        .prop_map(|(cubes, reversed)| {
+            let mut cubes: Vec<(&str, u32)> =
+                cubes.into_iter().map(|(i, n)| (COLORS[i], n)).collect();
+            if reversed {
+                cubes.reverse();
+            }
+            Game::build_full(&cubes)
+        })
+}
+
+fn session() -> impl Strategy<Value = Session> {
+    (1..1000u32, vec(game(), 1..6)).prop_map(|(id, games)| {
+        let mut session = Session::build(id);
+        session.set_games(games);
+        session
+    })
+}
+
+proptest! {
+    #[test]
+    fn printing_then_parsing_gives_the_session_back(session in session()) {
+        let text = session.to_string();
+        prop_assert_eq!(text.parse::<Session>().unwrap(), session);
+    }
+
+    #[test]
+    fn normalizing_keeps_the_cubes_and_is_idempotent(sessions in vec(session(), 1..5)) {
+        let input: String = sessions.iter().map(|s| format!("{}\n", s)).collect();
+        let normalized = day_2::normalize(&input).unwrap();
+        prop_assert_eq!(day_2::normalize(&normalized).unwrap(), normalized.clone());
+
+        for (session, line) in sessions.iter().zip(normalized.lines()) {
+            let parsed = line.parse::<Session>().unwrap();
+            prop_assert_eq!(parsed.id(), session.id());
+            for (before, after) in session.games().iter().zip(parsed.games()) {
+                for &color in COLORS.iter() {
+                    prop_assert_eq!(before.count(color), after.count(color));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn normalizing_fixes_spacing_and_repeated_colors() {
+    let input = "Game 1:  4 red,3 blue, 1 red ;2 green\nGame 2: 1 teal, 2 blue, 3 green\n";
+    assert_eq!(
+        day_2::normalize(input).unwrap(),
+        "Game 1: 5 red, 3 blue; 2 green\nGame 2: 3 green, 2 blue, 1 teal\n"
+    );
+}
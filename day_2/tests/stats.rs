@@ -0,0 +1,49 @@
+mod common;
+
+use common::sample;
+use day_2::{Session, Stats};
+
+fn sample_stats() -> Stats {
+    Stats::build(&sample())
+}
+
+#[test]
+fn summarizes_each_color_of_the_sample() {
+    let stats = sample_stats();
+    assert_eq!(stats.sessions, 5);
+    assert_eq!(stats.min_bag.to_string(), "20 red, 13 green, 15 blue");
+
+    let red = &stats.colors[0];
+    assert_eq!((red.color.as_str(), red.draws, red.max), ("red", 11, 20));
+    assert!((red.mean - 61.0 / 11.0).abs() < 1e-9);
+    assert_eq!(red.distribution.get(&1), Some(&4));
+    assert_eq!(red.tight_sessions, [3]);
+    assert_eq!(stats.colors[2].tight_sessions, [4]);
+}
+
+#[test]
+fn json_lists_colors_in_bag_order() {
+    let json = sample_stats().to_json();
+    assert_eq!(json["min_bag"]["blue"], 15);
+    assert_eq!(json["colors"][1]["color"], "green");
+    assert_eq!(json["colors"][1]["distribution"][0]["drawn"], 1);
+    assert_eq!(json["colors"][1]["distribution"][0]["draws"], 2);
+}
+
+#[test]
+fn large_counts_are_summarized() {
+    let session: Session = "Game 1: 4000000000 red; 4000000000 red, 1 blue"
+        .parse()
+        .unwrap();
+    let stats = Stats::build(&[session]);
+    assert_eq!(stats.colors[0].mean, 4000000000.0);
+
+    let table = stats.to_table();
+    let counts: Vec<&str> = table
+        .lines()
+        .rev()
+        .take(2)
+        .map(|l| l.split(' ').next().unwrap())
+        .collect();
+    assert_eq!(counts, ["4000000000", "1"]);
+}
@@ -0,0 +1,155 @@
+use crate::is_symbol;
+use std::fmt;
+use std::str::FromStr;
+
+// How many numbers must touch a symbol for it to be a gear
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Count {
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+impl Count {
+    pub fn allows(&self, numbers: usize) -> bool {
+        match *self {
+            Count::Exactly(n) => numbers == n,
+            Count::AtLeast(n) => numbers >= n,
+        }
+    }
+}
+
+// `N` for exactly N numbers, `N+` for N or more
+impl FromStr for Count {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (n, count): (&str, fn(usize) -> Count) = match s.strip_suffix('+') {
+            Some(n) => (n, Count::AtLeast),
+            None => (s, Count::Exactly),
+        };
+        match n.parse::<usize>() {
+            Ok(n) if n > 0 => Ok(count(n)),
+            _ => Err(format!(
+                "`{}` is not a count, expected N or N+ with N at least 1",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Count::Exactly(n) => write!(f, "{}", n),
+            Count::AtLeast(n) => write!(f, "{}+", n),
+        }
+    }
+}
+
+// What a gear's numbers add up to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Aggregate {
+    // None when the result doesn't fit in 64 bits
+    pub fn apply(&self, values: &[u32]) -> Option<u64> {
+        let mut values = values.iter().map(|&v| v as u64);
+        match self {
+            Aggregate::Product => values.try_fold(1u64, |acc, v| acc.checked_mul(v)),
+            Aggregate::Sum => values.try_fold(0u64, |acc, v| acc.checked_add(v)),
+            Aggregate::Max => Some(values.max().unwrap_or(0)),
+        }
+    }
+}
+
+impl FromStr for Aggregate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "product" => Ok(Aggregate::Product),
+            "sum" => Ok(Aggregate::Sum),
+            "max" => Ok(Aggregate::Max),
+            _ => Err(format!(
+                "`{}` is not an aggregate, expected `product`, `sum` or `max`",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Aggregate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Aggregate::Product => "product",
+            Aggregate::Sum => "sum",
+            Aggregate::Max => "max",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// Which symbols are gears and what each one is worth. The puzzle's gears are
+// `*` touching exactly two numbers, worth their product.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GearRule {
+    symbols: Vec<u8>,
+    pub count: Count,
+    pub aggregate: Aggregate,
+}
+
+impl Default for GearRule {
+    fn default() -> Self {
+        GearRule {
+            symbols: vec![b'*'],
+            count: Count::Exactly(2),
+            aggregate: Aggregate::Product,
+        }
+    }
+}
+
+impl GearRule {
+    pub fn build(symbols: &str, count: Count, aggregate: Aggregate) -> Result<Self, String> {
+        if symbols.is_empty() {
+            return Err("a gear rule needs at least one symbol".to_owned());
+        }
+        if let Some(c) = symbols
+            .chars()
+            .find(|&c| !c.is_ascii() || !is_symbol(c as u8))
+        {
+            return Err(format!("`{}` is not a schematic symbol", c));
+        }
+
+        Ok(GearRule {
+            symbols: symbols.bytes().collect(),
+            count,
+            aggregate,
+        })
+    }
+
+    pub fn symbols(&self) -> &[u8] {
+        &self.symbols
+    }
+
+    // The value of a symbol touching these numbers, or None if it isn't a gear
+    pub fn value(&self, symbol: u8, numbers: &[u32]) -> Result<Option<u64>, String> {
+        if !self.symbols.contains(&symbol) || !self.count.allows(numbers.len()) {
+            return Ok(None);
+        }
+        match self.aggregate.apply(numbers) {
+            Some(value) => Ok(Some(value)),
+            None => {
+                let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+                Err(format!(
+                    "the {} of {} doesn't fit in 64 bits",
+                    self.aggregate,
+                    numbers.join(", ")
+                ))
+            }
+        }
+    }
+}
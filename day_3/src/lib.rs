@@ -0,0 +1,86 @@
+use aoc_core::{ParseError, Solution};
+
+mod gear;
+mod render;
+mod schematic;
+
+pub use gear::{Aggregate, Count, GearRule};
+pub use render::{annotate, render, Format, Mark};
+pub use schematic::{Number, Schematic, Symbol};
+
+fn is_symbol(val: u8) -> bool {
+    (33..=45).contains(&val)
+        || val == 47
+        || (58..=64).contains(&val)
+        || (91..=96).contains(&val)
+        || (123..=126).contains(&val)
+}
+
+fn is_digit(val: u8) -> bool {
+    (48..=57).contains(&val)
+}
+
+fn check_adjacency_part_number(schematic: &Schematic, number: usize) -> bool {
+    !schematic.symbols_around(number).is_empty()
+}
+
+// The value of a symbol under the rule, or None for a symbol that isn't a gear
+fn adjacency_gear_ratio(
+    schematic: &Schematic,
+    symbol: usize,
+    rule: &GearRule,
+) -> Result<Option<u64>, String> {
+    let numbers = schematic.numbers();
+    let values: Vec<u32> = schematic
+        .numbers_around(symbol)
+        .iter()
+        .map(|&i| numbers[i].value)
+        .collect();
+    let Symbol { position, symbol } = schematic.symbols()[symbol];
+    rule.value(symbol, &values).map_err(|e| {
+        format!(
+            "the gear at row {}, column {}: {}",
+            position.1 + 1,
+            position.0 + 1,
+            e
+        )
+    })
+}
+
+// Sum of the values of every gear under the rule
+pub fn gear_sum(schematic: &Schematic, rule: &GearRule) -> Result<u64, String> {
+    (0..schematic.symbols().len()).try_fold(0u64, |sum, i| {
+        let value = adjacency_gear_ratio(schematic, i, rule)?.unwrap_or(0);
+        sum.checked_add(value)
+            .ok_or_else(|| "the sum of the gears doesn't fit in 64 bits".to_owned())
+    })
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Schematic;
+    type Answer1 = u64;
+    type Answer2 = Result<u64, String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Schematic::parse(input)
+    }
+
+    fn part_1(schematic: &Self::Input) -> u64 {
+        schematic
+            .numbers()
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| check_adjacency_part_number(schematic, i))
+            .map(|(_, number)| number.value as u64)
+            .sum()
+    }
+
+    fn part_2(schematic: &Self::Input) -> Result<u64, String> {
+        gear_sum(schematic, &GearRule::default())
+    }
+}
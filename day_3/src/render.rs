@@ -0,0 +1,221 @@
+use crate::{adjacency_gear_ratio, check_adjacency_part_number, GearRule, Schematic};
+use aoc_core::Grid;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ansi,
+    Html,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ansi" => Ok(Format::Ansi),
+            "html" => Ok(Format::Html),
+            _ => Err(format!(
+                "`{}` is not a format, expected `ansi` or `html`",
+                s
+            )),
+        }
+    }
+}
+
+// What a cell of the schematic counted as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    Blank,
+    PartNumber,
+    IgnoredNumber,
+    // With its value under the gear rule
+    Gear(u64),
+    // A gear symbol without the numbers the rule asks for
+    NotGear,
+    Symbol,
+}
+
+impl Mark {
+    fn label(&self) -> &'static str {
+        match self {
+            Mark::Blank => "blank",
+            Mark::PartNumber => "part number",
+            Mark::IgnoredNumber => "ignored number",
+            Mark::Gear(_) => "gear",
+            Mark::NotGear => "not a gear",
+            Mark::Symbol => "symbol",
+        }
+    }
+
+    fn ansi(&self) -> Option<&'static str> {
+        match self {
+            Mark::Blank => None,
+            Mark::PartNumber => Some("32"),
+            Mark::IgnoredNumber => Some("31"),
+            Mark::Gear(_) => Some("1;33"),
+            Mark::NotGear => Some("35"),
+            Mark::Symbol => Some("36"),
+        }
+    }
+
+    fn css_class(&self) -> Option<&'static str> {
+        match self {
+            Mark::Blank => None,
+            Mark::PartNumber => Some("part"),
+            Mark::IgnoredNumber => Some("ignored"),
+            Mark::Gear(_) => Some("gear"),
+            Mark::NotGear => Some("not-gear"),
+            Mark::Symbol => Some("symbol"),
+        }
+    }
+}
+
+const LEGEND: [Mark; 5] = [
+    Mark::PartNumber,
+    Mark::IgnoredNumber,
+    Mark::Gear(0),
+    Mark::NotGear,
+    Mark::Symbol,
+];
+
+// Marks every cell with what the answers made of it, failing like the answers
+// on a gear too large for 64 bits
+pub fn annotate(schematic: &Schematic, rule: &GearRule) -> Result<Grid<Mark>, String> {
+    let grid = schematic.grid();
+    let mut marks = Grid::build(grid.width(), grid.height(), Mark::Blank);
+
+    for (i, number) in schematic.numbers().iter().enumerate() {
+        let mark = match check_adjacency_part_number(schematic, i) {
+            true => Mark::PartNumber,
+            false => Mark::IgnoredNumber,
+        };
+        for x in number.start..=number.end {
+            marks[(x, number.row)] = mark;
+        }
+    }
+    for (i, symbol) in schematic.symbols().iter().enumerate() {
+        marks[symbol.position] = match adjacency_gear_ratio(schematic, i, rule)? {
+            Some(value) => Mark::Gear(value),
+            None if rule.symbols().contains(&symbol.symbol) => Mark::NotGear,
+            None => Mark::Symbol,
+        };
+    }
+
+    Ok(marks)
+}
+
+// Each row split into runs of cells marked alike
+fn runs(schematic: &Schematic, marks: &Grid<Mark>) -> Vec<Vec<(Mark, String)>> {
+    schematic
+        .grid()
+        .rows()
+        .zip(marks.rows())
+        .map(|(cells, marks)| {
+            let mut runs: Vec<(Mark, String)> = vec![];
+            for (&cell, &mark) in cells.iter().zip(marks.iter()) {
+                match runs.last_mut() {
+                    Some((last, text)) if *last == mark => text.push(cell as char),
+                    _ => runs.push((mark, (cell as char).to_string())),
+                }
+            }
+            runs
+        })
+        .collect()
+}
+
+// The schematic colored for a terminal, followed by a legend
+fn to_ansi(schematic: &Schematic, marks: &Grid<Mark>) -> String {
+    let paint = |mark: Mark, text: &str| match mark.ansi() {
+        Some(code) => format!("\x1b[{}m{}\x1b[0m", code, text),
+        None => text.to_owned(),
+    };
+
+    let mut out = String::new();
+    for row in runs(schematic, marks) {
+        for (mark, text) in row {
+            out.push_str(&paint(mark, &text));
+        }
+        out.push('\n');
+    }
+
+    let legend: Vec<String> = LEGEND.iter().map(|m| paint(*m, m.label())).collect();
+    out.push('\n');
+    out.push_str(&legend.join("  "));
+    out.push('\n');
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// The same view as a standalone page, gears showing their value on hover
+fn to_html(schematic: &Schematic, marks: &Grid<Mark>) -> String {
+    let span = |mark: Mark, text: &str| {
+        let text = escape_html(text);
+        match (mark, mark.css_class()) {
+            (Mark::Gear(value), Some(class)) => {
+                format!(
+                    "<span class=\"{}\" title=\"{}\">{}</span>",
+                    class, value, text
+                )
+            }
+            (_, Some(class)) => format!("<span class=\"{}\">{}</span>", class, text),
+            (_, None) => text,
+        }
+    };
+
+    let mut body = String::new();
+    for row in runs(schematic, marks) {
+        for (mark, text) in row {
+            body.push_str(&span(mark, &text));
+        }
+        body.push('\n');
+    }
+    let legend: Vec<String> = LEGEND
+        .iter()
+        .map(|m| {
+            format!(
+                "<span class=\"{}\">{}</span>",
+                m.css_class().unwrap(),
+                m.label()
+            )
+        })
+        .collect();
+
+    format!(
+        "<!DOCTYPE html>\n\
+         <html>\n\
+         <head>\n\
+         <meta charset=\"utf-8\">\n\
+         <title>Engine schematic</title>\n\
+         <style>\n\
+         body {{ background: #0f0f23; color: #666; font-family: monospace; }}\n\
+         .part {{ color: #3c3; }}\n\
+         .ignored {{ color: #c33; }}\n\
+         .gear {{ color: #ff6; font-weight: bold; }}\n\
+         .not-gear {{ color: #c3c; }}\n\
+         .symbol {{ color: #3cc; }}\n\
+         </style>\n\
+         </head>\n\
+         <body>\n\
+         <pre>\n{}</pre>\n\
+         <p>{}</p>\n\
+         </body>\n\
+         </html>\n",
+        body,
+        legend.join(" ")
+    )
+}
+
+pub fn render(schematic: &Schematic, rule: &GearRule, format: Format) -> Result<String, String> {
+    let marks = annotate(schematic, rule)?;
+    Ok(match format {
+        Format::Ansi => to_ansi(schematic, &marks),
+        Format::Html => to_html(schematic, &marks),
+    })
+}
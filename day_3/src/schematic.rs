@@ -0,0 +1,170 @@
+use crate::{is_digit, is_symbol};
+use aoc_core::{Grid, ParseError};
+use std::collections::BTreeSet;
+
+// A run of digits within a row, from column `start` to column `end` included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub position: (usize, usize),
+    pub symbol: u8,
+}
+
+// What covers a cell, by index into the numbers or the symbols
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Number(usize),
+    Symbol(usize),
+}
+
+// The numbers and symbols of an engine schematic, with which of them touch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schematic {
+    grid: Grid<u8>,
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    tokens: Grid<Option<Token>>,
+    // By index, the symbols around each number and the numbers around each
+    // symbol
+    symbols_around: Vec<Vec<usize>>,
+    numbers_around: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    // Reads the grid of an ASCII schematic, rejecting numbers too large for
+    // 32 bits
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_with(input, "an ASCII character", |c| {
+            c.is_ascii().then_some(c as u8)
+        })?;
+        let rows: Vec<&str> = aoc_core::lines(input).collect();
+
+        Schematic::build(grid).map_err(|(row, start)| {
+            let line = rows[row];
+            let end = line[start..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(line.len(), |end| start + end);
+            ParseError::new(input, &line[start..end], "a number below 4294967296")
+        })
+    }
+
+    // Fails with the row and first column of a number too large
+    fn build(grid: Grid<u8>) -> Result<Self, (usize, usize)> {
+        let mut numbers: Vec<Number> = vec![];
+        let mut symbols: Vec<Symbol> = vec![];
+        let mut tokens = Grid::build(grid.width(), grid.height(), None);
+
+        for ((x, y), &cell) in grid.iter() {
+            if is_digit(cell) {
+                let digit = (cell - b'0') as u32;
+                match numbers.last_mut() {
+                    Some(number) if number.row == y && number.end + 1 == x => {
+                        number.end = x;
+                        number.value = number
+                            .value
+                            .checked_mul(10)
+                            .and_then(|value| value.checked_add(digit))
+                            .ok_or((y, number.start))?;
+                    }
+                    _ => numbers.push(Number {
+                        row: y,
+                        start: x,
+                        end: x,
+                        value: digit,
+                    }),
+                }
+                tokens[(x, y)] = Some(Token::Number(numbers.len() - 1));
+            } else if is_symbol(cell) {
+                symbols.push(Symbol {
+                    position: (x, y),
+                    symbol: cell,
+                });
+                tokens[(x, y)] = Some(Token::Symbol(symbols.len() - 1));
+            }
+        }
+
+        let mut symbols_around = vec![vec![]; numbers.len()];
+        let mut numbers_around = vec![];
+        for (s, symbol) in symbols.iter().enumerate() {
+            let around = touching(&tokens, symbol.position);
+            for &n in around.iter() {
+                symbols_around[n].push(s);
+            }
+            numbers_around.push(around.into_iter().collect());
+        }
+
+        Ok(Schematic {
+            grid,
+            numbers,
+            symbols,
+            tokens,
+            symbols_around,
+            numbers_around,
+        })
+    }
+
+    pub fn grid(&self) -> &Grid<u8> {
+        &self.grid
+    }
+
+    // In reading order
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    // In reading order
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    // Index of the number covering the position, if any
+    pub fn number_at(&self, position: (usize, usize)) -> Option<usize> {
+        match self.tokens.get(position) {
+            Some(Some(Token::Number(i))) => Some(*i),
+            _ => None,
+        }
+    }
+
+    // Index of the symbol at the position, if any
+    pub fn symbol_at(&self, position: (usize, usize)) -> Option<usize> {
+        match self.tokens.get(position) {
+            Some(Some(Token::Symbol(i))) => Some(*i),
+            _ => None,
+        }
+    }
+
+    // Indices of the symbols touching a number, even diagonally
+    pub fn symbols_around(&self, number: usize) -> &[usize] {
+        &self.symbols_around[number]
+    }
+
+    // Indices of the numbers touching a symbol, even diagonally, each once
+    // and in reading order
+    pub fn numbers_around(&self, symbol: usize) -> &[usize] {
+        &self.numbers_around[symbol]
+    }
+
+    // Indices of the numbers touching any position, even diagonally
+    pub fn numbers_touching(&self, position: (usize, usize)) -> BTreeSet<usize> {
+        touching(&self.tokens, position)
+    }
+}
+
+// A number spans several cells, so it is met as many times as it has cells
+// around the position and the set keeps it once
+fn touching(tokens: &Grid<Option<Token>>, position: (usize, usize)) -> BTreeSet<usize> {
+    tokens
+        .neighbors_8(position)
+        .filter_map(|p| match tokens[p] {
+            Some(Token::Number(n)) => Some(n),
+            _ => None,
+        })
+        .collect()
+}
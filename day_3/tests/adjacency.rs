@@ -0,0 +1,138 @@
+// Checks the schematic's tokens and adjacency against a brute-force reading of
+// random schematics
+use aoc_core::Solution;
+use day_3::Day3;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use std::collections::BTreeSet;
+
+// (row, start, end, value) of every run of digits, in reading order
+fn brute_numbers(rows: &[String]) -> Vec<(usize, usize, usize, u32)> {
+    let mut numbers = vec![];
+    for (y, row) in rows.iter().enumerate() {
+        let bytes = row.as_bytes();
+        let mut x = 0;
+        while x < bytes.len() {
+            if !bytes[x].is_ascii_digit() {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            while x < bytes.len() && bytes[x].is_ascii_digit() {
+                x += 1;
+            }
+            numbers.push((y, start, x - 1, row[start..x].parse().unwrap()));
+        }
+    }
+    numbers
+}
+
+fn brute_symbols(rows: &[String]) -> Vec<((usize, usize), u8)> {
+    let mut symbols = vec![];
+    for (y, row) in rows.iter().enumerate() {
+        for (x, b) in row.bytes().enumerate() {
+            if !b.is_ascii_digit() && b != b'.' {
+                symbols.push(((x, y), b));
+            }
+        }
+    }
+    symbols
+}
+
+// Indices of the numbers with a cell among the eight around the position
+fn brute_touching(rows: &[String], (x, y): (usize, usize)) -> BTreeSet<usize> {
+    brute_numbers(rows)
+        .iter()
+        .enumerate()
+        .filter(|(_, &(row, start, end, _))| {
+            (start..=end)
+                .any(|cx| (cx, row) != (x, y) && cx.abs_diff(x) <= 1 && row.abs_diff(y) <= 1)
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+fn schematic() -> impl Strategy<Value = Vec<String>> {
+    let cell = prop_oneof![
+        4 => Just('.'),
+        4 => proptest::char::range('0', '9'),
+        1 => proptest::sample::select(vec!['*', '#', '+', '$', '/', '=']),
+    ];
+    // Narrow enough for every number and gear ratio to fit
+    (1..7usize, 1..8usize).prop_flat_map(move |(width, height)| {
+        vec(
+            vec(cell.clone(), width).prop_map(|row| row.into_iter().collect::<String>()),
+            height,
+        )
+    })
+}
+
+proptest! {
+    #[test]
+    fn tokens_match_a_brute_force_scan(rows in schematic()) {
+        let schematic = Day3::parse(&rows.join("\n")).unwrap();
+        let numbers: Vec<(usize, usize, usize, u32)> = schematic
+            .numbers()
+            .iter()
+            .map(|n| (n.row, n.start, n.end, n.value))
+            .collect();
+        prop_assert_eq!(numbers, brute_numbers(&rows));
+
+        let symbols: Vec<((usize, usize), u8)> =
+            schematic.symbols().iter().map(|s| (s.position, s.symbol)).collect();
+        prop_assert_eq!(symbols, brute_symbols(&rows));
+    }
+
+    #[test]
+    fn every_distinct_number_around_a_position_is_found(rows in schematic()) {
+        let schematic = Day3::parse(&rows.join("\n")).unwrap();
+        for (i, symbol) in schematic.symbols().iter().enumerate() {
+            let expected = brute_touching(&rows, symbol.position);
+            let around: BTreeSet<usize> = schematic.numbers_around(i).iter().copied().collect();
+            prop_assert_eq!(around.len(), schematic.numbers_around(i).len());
+            prop_assert_eq!(&around, &expected);
+            for &n in expected.iter() {
+                prop_assert!(schematic.symbols_around(n).contains(&i));
+            }
+        }
+
+        let grid = schematic.grid();
+        for ((x, y), _) in grid.iter() {
+            prop_assert_eq!(schematic.numbers_touching((x, y)), brute_touching(&rows, (x, y)));
+        }
+    }
+
+    #[test]
+    fn answers_match_a_brute_force_count(rows in schematic()) {
+        let schematic = Day3::parse(&rows.join("\n")).unwrap();
+        let numbers = brute_numbers(&rows);
+        let symbols = brute_symbols(&rows);
+
+        let part_1: u64 = numbers
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| symbols.iter().any(|&(p, _)| brute_touching(&rows, p).contains(&i)))
+            .map(|(_, n)| n.3 as u64)
+            .sum();
+        let part_2: u64 = symbols
+            .iter()
+            .filter(|&&(_, s)| s == b'*')
+            .map(|&(p, _)| brute_touching(&rows, p))
+            .filter(|around| around.len() == 2)
+            .map(|around| around.iter().map(|&i| numbers[i].3 as u64).product::<u64>())
+            .sum();
+
+        prop_assert_eq!(Day3::part_1(&schematic), part_1);
+        prop_assert_eq!(Day3::part_2(&schematic), Ok(part_2));
+    }
+}
+
+#[test]
+fn a_symbol_can_touch_more_than_two_numbers() {
+    let schematic = Day3::parse("1.2\n3*4\n5.6\n").unwrap();
+    assert_eq!(schematic.numbers_around(0), [0, 1, 2, 3, 4, 5]);
+
+    let schematic = Day3::parse("123\n.*.\n4.5\n").unwrap();
+    assert_eq!(schematic.numbers_around(0), [0, 1, 2]);
+    assert_eq!(Day3::part_2(&schematic), Ok(0));
+}
@@ -0,0 +1,13 @@
+use aoc_core::Solution;
+use day_3::{Day3, Schematic};
+use std::fs;
+use std::path::Path;
+
+// The puzzle's example schematic, as text and parsed
+pub fn sample_text() -> String {
+    fs::read_to_string(Path::new(Day3::DIR).join("sample_input.txt")).unwrap()
+}
+
+pub fn sample() -> Schematic {
+    Day3::parse(&sample_text()).unwrap()
+}
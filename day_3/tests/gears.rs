@@ -0,0 +1,53 @@
+mod common;
+
+use aoc_core::Solution;
+use common::sample;
+use day_3::{Aggregate, Count, Day3, GearRule};
+
+fn sum(symbols: &str, count: &str, aggregate: &str) -> u64 {
+    let rule =
+        GearRule::build(symbols, count.parse().unwrap(), aggregate.parse().unwrap()).unwrap();
+    day_3::gear_sum(&sample(), &rule).unwrap()
+}
+
+#[test]
+fn the_default_rule_is_the_puzzles() {
+    assert_eq!(day_3::gear_sum(&sample(), &GearRule::default()), Ok(467835));
+    assert_eq!(sum("*", "2", "product"), 467835);
+}
+
+#[test]
+fn rules_pick_symbols_counts_and_aggregates() {
+    assert_eq!(sum("*", "1+", "max"), 467 + 617 + 755);
+    assert_eq!(sum("*", "1", "sum"), 617);
+    assert_eq!(sum("#+$", "1", "product"), 633 + 592 + 664);
+    assert_eq!(sum("*#", "3+", "sum"), 0);
+}
+
+#[test]
+fn gears_too_large_for_64_bits_are_errors() {
+    let schematic = Day3::parse("4000000000.4000000000\n4000000000*4000000000\n").unwrap();
+    let rule = GearRule::build("*", Count::Exactly(4), Aggregate::Product).unwrap();
+    assert_eq!(
+        day_3::gear_sum(&schematic, &rule),
+        Err(
+            "the gear at row 2, column 11: the product of 4000000000, 4000000000, \
+             4000000000, 4000000000 doesn't fit in 64 bits"
+                .to_owned()
+        )
+    );
+    assert!(day_3::annotate(&schematic, &rule).is_err());
+
+    let rule = GearRule::build("*", Count::Exactly(4), Aggregate::Sum).unwrap();
+    assert_eq!(day_3::gear_sum(&schematic, &rule), Ok(16000000000));
+}
+
+#[test]
+fn rejects_malformed_rules() {
+    assert_eq!("3+".parse::<Count>(), Ok(Count::AtLeast(3)));
+    assert!("0".parse::<Count>().is_err());
+    assert!("+".parse::<Count>().is_err());
+    assert!("mean".parse::<Aggregate>().is_err());
+    assert!(GearRule::build("*.", Count::Exactly(2), Aggregate::Sum).is_err());
+    assert!(GearRule::build("", Count::Exactly(2), Aggregate::Sum).is_err());
+}
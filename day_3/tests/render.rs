@@ -0,0 +1,53 @@
+mod common;
+
+use aoc_core::Solution;
+use common::{sample, sample_text};
+use day_3::{Aggregate, Count, Day3, Format, GearRule, Mark};
+
+#[test]
+fn marks_what_the_answers_counted() {
+    let schematic = sample();
+    let marks = day_3::annotate(&schematic, &GearRule::default()).unwrap();
+    assert_eq!(marks[(0, 0)], Mark::PartNumber);
+    assert_eq!(marks[(7, 0)], Mark::IgnoredNumber);
+    assert_eq!(marks[(3, 1)], Mark::Gear(16345));
+    assert_eq!(marks[(3, 4)], Mark::NotGear);
+    assert_eq!(marks[(6, 3)], Mark::Symbol);
+    assert_eq!(marks[(0, 1)], Mark::Blank);
+
+    let rule = GearRule::build("*", Count::AtLeast(1), Aggregate::Sum).unwrap();
+    let marks = day_3::annotate(&schematic, &rule).unwrap();
+    assert_eq!(marks[(3, 4)], Mark::Gear(617));
+}
+
+#[test]
+fn ansi_keeps_the_schematic_text() {
+    let (text, schematic) = (sample_text(), sample());
+    let ansi = day_3::render(&schematic, &GearRule::default(), Format::Ansi).unwrap();
+    assert!(ansi.starts_with("\x1b[32m467\x1b[0m..\x1b[31m114\x1b[0m..\n"));
+
+    let mut plain = String::new();
+    let mut in_escape = false;
+    for c in ansi.chars() {
+        match c {
+            '\x1b' => in_escape = true,
+            'm' if in_escape => in_escape = false,
+            _ if in_escape => {}
+            _ => plain.push(c),
+        }
+    }
+    assert!(plain.starts_with(&text), "{}", plain);
+}
+
+#[test]
+fn html_escapes_symbols_and_titles_gears() {
+    let schematic = sample();
+    let html = day_3::render(&schematic, &GearRule::default(), Format::Html).unwrap();
+    assert!(html.contains("...<span class=\"gear\" title=\"16345\">*</span>......\n"));
+    assert!(html.contains("<span class=\"part\">617</span><span class=\"not-gear\">*</span>"));
+
+    let schematic = Day3::parse("1<2&3\n").unwrap();
+    let html = day_3::render(&schematic, &GearRule::default(), Format::Html).unwrap();
+    assert!(html.contains("<span class=\"symbol\">&lt;</span>"));
+    assert!(html.contains("<span class=\"symbol\">&amp;</span>"));
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use aoc_core::Solution;
+use common::sample;
+use day_3::{Day3, Number};
+
+#[test]
+fn tokenizes_numbers_and_symbols_in_reading_order() {
+    let schematic = sample();
+    let values: Vec<u32> = schematic.numbers().iter().map(|n| n.value).collect();
+    assert_eq!(values, [467, 114, 35, 633, 617, 58, 592, 755, 664, 598]);
+    assert_eq!(
+        schematic.numbers()[3],
+        Number {
+            row: 2,
+            start: 6,
+            end: 8,
+            value: 633
+        }
+    );
+
+    let symbols: String = schematic
+        .symbols()
+        .iter()
+        .map(|s| s.symbol as char)
+        .collect();
+    assert_eq!(symbols, "*#*+$*");
+    assert_eq!(schematic.symbols()[3].position, (5, 5));
+    assert_eq!(schematic.number_at((2, 9)), Some(8));
+    assert_eq!(schematic.symbol_at((3, 8)), Some(4));
+    assert_eq!(schematic.number_at((4, 0)), None);
+}
+
+#[test]
+fn indexes_what_touches_what() {
+    let schematic = sample();
+    assert_eq!(schematic.numbers_around(0), [0, 2]);
+    assert_eq!(schematic.numbers_around(5), [7, 9]);
+    assert_eq!(schematic.numbers_around(2), [4]);
+    assert!(schematic.symbols_around(1).is_empty());
+    assert_eq!(schematic.symbols_around(3), [1]);
+}
+
+#[test]
+fn counts_each_touching_number_once() {
+    let schematic = Day3::parse("1.2\n.*.\n3.4\n").unwrap();
+    assert_eq!(schematic.numbers_around(0), [0, 1, 2, 3]);
+
+    let schematic = Day3::parse("12.\n.*.\n345\n").unwrap();
+    assert_eq!(schematic.numbers_around(0), [0, 1]);
+}
+
+#[test]
+fn numbers_too_large_for_32_bits_are_rejected() {
+    let error = Day3::parse("..............\n.*99999999999.\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+    assert_eq!(error.found, "99999999999");
+
+    // Numbers that fit still add up past 32 bits
+    let schematic = Day3::parse("4000000000*4000000000\n").unwrap();
+    assert_eq!(Day3::part_1(&schematic), 8000000000);
+}
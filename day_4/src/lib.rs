@@ -0,0 +1,163 @@
+use aoc_core::{ParseError, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct Card {
+    number: u32,
+    winning_numbers: HashSet<u32>,
+    card_numbers: HashSet<u32>,
+}
+
+impl Card {
+    fn build(number: u32) -> Card {
+        Card {
+            number,
+            winning_numbers: HashSet::new(),
+            card_numbers: HashSet::new(),
+        }
+    }
+
+    fn add_winning_number(&mut self, number: u32) {
+        self.winning_numbers.insert(number);
+    }
+
+    fn add_card_number(&mut self, number: u32) {
+        self.card_numbers.insert(number);
+    }
+
+    fn score_length(&self) -> usize {
+        self.winning_numbers
+            .intersection(&self.card_numbers)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    fn score(&self) -> u32 {
+        let length = self.score_length();
+
+        if length == 0 {
+            return 0;
+        }
+
+        u32::pow(2, (length - 1) as u32)
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(rest) = s.strip_prefix("Card") else {
+            let word = s.split(' ').next().unwrap();
+            return Err(ParseError::new(s, word, "`Card`"));
+        };
+        let Some((card_id_str, rest)) = rest.split_once(":") else {
+            return Err(ParseError::at_end(s, "`:`"));
+        };
+        let card_id_str = card_id_str.trim();
+        let card_id = card_id_str
+            .parse::<u32>()
+            .map_err(|_| ParseError::new(s, card_id_str, "a card id"))?;
+        let mut card = Card::build(card_id);
+
+        let Some((winning_numbers_str, card_numbers_str)) = rest.split_once("|") else {
+            return Err(ParseError::at_end(s, "`|`"));
+        };
+
+        for num_str in winning_numbers_str.split(" ").filter(|&s| !s.is_empty()) {
+            let num = num_str
+                .parse::<u32>()
+                .map_err(|_| ParseError::new(s, num_str, "a winning number"))?;
+            card.add_winning_number(num);
+        }
+
+        for num_str in card_numbers_str.split(" ").filter(|&s| !s.is_empty()) {
+            let num = num_str
+                .parse::<u32>()
+                .map_err(|_| ParseError::new(s, num_str, "a card number"))?;
+            card.add_card_number(num);
+        }
+
+        return Ok(card);
+    }
+}
+
+#[derive(Clone)]
+pub struct CardWins {
+    cards: Vec<Card>,
+    won_duplicates: HashMap<u32, u32>,
+}
+
+impl CardWins {
+    fn build(cards: Vec<Card>) -> Self {
+        CardWins {
+            cards,
+            won_duplicates: HashMap::new(),
+        }
+    }
+
+    fn get_won_duplicates(&self, card_id: u32) -> u32 {
+        match self.won_duplicates.get(&card_id) {
+            Some(&num) => num,
+            None => 0,
+        }
+    }
+
+    fn get_won_cards(&mut self, card: &Card) -> u32 {
+        let cards_length = self.cards.len();
+        let count = card.score_length();
+        let curr_card_duplicates = self.get_won_duplicates(card.number);
+
+        let upper_bound = if card.number + count as u32 > cards_length as u32 {
+            (cards_length - 1) as u32
+        } else {
+            card.number + count as u32
+        };
+
+        for card_id in (card.number + 1)..=upper_bound {
+            let duplicates = self.get_won_duplicates(card_id);
+            self.won_duplicates
+                .insert(card_id, duplicates + curr_card_duplicates + 1);
+        }
+
+        return 1 + curr_card_duplicates;
+    }
+
+    fn get_score(&self) -> u32 {
+        self.cards.iter().fold(0, |acc, c| acc + c.score())
+    }
+
+    fn get_total_cards(&mut self) -> u32 {
+        // There must be a better way to handle borrowing here
+        let cards = self.cards.clone();
+
+        cards.iter().fold(0, |acc, c| acc + self.get_won_cards(c))
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = CardWins;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let cards = aoc_core::parse_lines(input)?;
+
+        Ok(CardWins::build(cards))
+    }
+
+    fn part_1(card_wins: &Self::Input) -> u32 {
+        card_wins.get_score()
+    }
+
+    fn part_2(card_wins: &Self::Input) -> u32 {
+        card_wins.clone().get_total_cards()
+    }
+}
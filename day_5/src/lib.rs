@@ -0,0 +1,286 @@
+use aoc_core::{ParseError, Solution};
+use rayon::prelude::*;
+use std::ops::Range;
+use std::str::FromStr;
+
+struct RangeMapping {
+    mapped_range: Option<Range<u64>>,
+    // Portions of the source range don't fit this mapping
+    unmapped_sections: Vec<Range<u64>>,
+}
+
+impl RangeMapping {
+    fn build(mapped_range: Option<Range<u64>>, unmapped_sections: Vec<Range<u64>>) -> Self {
+        Self {
+            mapped_range,
+            unmapped_sections,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MapItem(Range<u64>, Range<u64>);
+
+impl MapItem {
+    fn build(source: u64, dest: u64, count: u64) -> MapItem {
+        MapItem(source..(source + count), dest..(dest + count))
+    }
+
+    fn map_value(&self, value: u64) -> bool {
+        self.0.contains(&value)
+    }
+
+    fn map_range(&self, range: &Range<u64>) -> RangeMapping {
+        let MapItem(source, dest) = self;
+
+        if range.start >= source.start && range.end <= source.end {
+            let mapped_range =
+                (dest.start + (range.start - source.start))..(dest.end - (source.end - range.end));
+            return RangeMapping::build(Some(mapped_range), vec![]);
+        } else if range.start < source.start && range.end <= source.end && range.end > source.start
+        {
+            let mapped_range = dest.start..(dest.end - (source.end - range.end));
+            let unmapped_section = range.start..source.start;
+            return RangeMapping::build(Some(mapped_range), vec![unmapped_section]);
+        } else if range.start >= source.start && range.end > source.end && range.start < source.end
+        {
+            let mapped_range = (dest.start + (range.start - source.start))..dest.end;
+            let unmapped_section = source.end..range.end;
+            return RangeMapping::build(Some(mapped_range), vec![unmapped_section]);
+        } else if range.start < source.start && range.end > source.end {
+            let mapped_range = dest.start..dest.end;
+            let unmapped_left = range.start..source.start;
+            let unmapped_right = source.end..range.end;
+            return RangeMapping::build(Some(mapped_range), vec![unmapped_left, unmapped_right]);
+        }
+
+        return RangeMapping::build(None, vec![range.clone()]);
+    }
+}
+
+#[derive(Debug)]
+#[allow(dead_code)]
+struct AgMap {
+    source: String,
+    destination: String,
+    map: Vec<MapItem>,
+}
+
+impl AgMap {
+    fn build(source: &str, destination: &str) -> Self {
+        AgMap {
+            source: String::from(source),
+            destination: String::from(destination),
+            map: vec![],
+        }
+    }
+
+    fn add_range(&mut self, source_start: u64, dest_start: u64, count: u64) {
+        self.map
+            .push(MapItem::build(source_start, dest_start, count));
+    }
+
+    fn map_value(&self, value: u64) -> u64 {
+        match self.map.iter().find(|map_item| map_item.map_value(value)) {
+            Some(MapItem(source, dest)) => dest.start + (value - source.start),
+            None => value,
+        }
+    }
+
+    fn map_range(&self, range: &Range<u64>) -> Vec<Range<u64>> {
+        let mut mapped_ranges: Vec<Range<u64>> = vec![];
+        let mut unmapped_ranges: Vec<Range<u64>> = vec![range.clone()];
+
+        for j in 0..self.map.len() {
+            for i in 0..unmapped_ranges.len() {
+                let mapping: RangeMapping = self.map[j].map_range(&unmapped_ranges[i]);
+                if let Some(mapped) = mapping.mapped_range {
+                    unmapped_ranges.remove(i);
+                    mapped_ranges.push(mapped);
+                    if !mapping.unmapped_sections.is_empty() {
+                        unmapped_ranges.extend(mapping.unmapped_sections);
+                    }
+                    break;
+                }
+            }
+        }
+
+        if mapped_ranges.is_empty() {
+            return vec![range.clone()];
+        }
+        mapped_ranges.extend(unmapped_ranges);
+        return mapped_ranges;
+    }
+}
+
+impl FromStr for AgMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let Some((source_to_destination_str, rest)) = s.split_once(":") else {
+            return Err(ParseError::at_end(s.lines().next().unwrap_or(s), "`:`"));
+        };
+        let Some((source_to_destination_str, _)) = source_to_destination_str.split_once(" ") else {
+            return Err(ParseError::new(
+                s,
+                source_to_destination_str,
+                "`<source>-to-<destination> map`",
+            ));
+        };
+        let strs: Vec<&str> = source_to_destination_str
+            .split('-')
+            .filter(|&s| s != "to")
+            .collect();
+        let [source, destination] = strs[..] else {
+            return Err(ParseError::new(
+                s,
+                source_to_destination_str,
+                "`<source>-to-<destination>`",
+            ));
+        };
+
+        let mut ag_map = AgMap::build(source, destination);
+
+        for line in aoc_core::lines(rest) {
+            let range_strs: Vec<&str> = line.split(" ").collect();
+
+            if range_strs.len() != 3 {
+                return Err(ParseError::new(s, line, "`<destination> <source> <count>`"));
+            }
+
+            let dest_start = range_strs[0]
+                .parse::<u64>()
+                .map_err(|_| ParseError::new(s, range_strs[0], "a destination start"))?;
+            let source_start = range_strs[1]
+                .parse::<u64>()
+                .map_err(|_| ParseError::new(s, range_strs[1], "a source start"))?;
+            let count = range_strs[2]
+                .parse::<u64>()
+                .map_err(|_| ParseError::new(s, range_strs[2], "a range length"))?;
+            if source_start.max(dest_start).checked_add(count).is_none() {
+                return Err(ParseError::new(
+                    s,
+                    range_strs[2],
+                    "a range length ending below 2^64",
+                ));
+            }
+
+            ag_map.add_range(source_start, dest_start, count);
+        }
+
+        return Ok(ag_map);
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<AgMap>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Almanac;
+    type Answer1 = Result<u64, String>;
+    type Answer2 = Result<u64, String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let Some((default_seed_str, rest)) = input.split_once("\n\n") else {
+            return Err(ParseError::at_end(input, "a blank line after the seeds"));
+        };
+        let Some((_, seed_list_str)) = default_seed_str.split_once(":") else {
+            return Err(ParseError::at_end(default_seed_str, "`seeds:`"));
+        };
+        let seeds = seed_list_str
+            .split(" ")
+            .filter(|&s| !s.is_empty())
+            .map(|s| {
+                s.parse::<u64>()
+                    .map_err(|_| ParseError::new(input, s, "a seed number"))
+            })
+            .collect::<Result<Vec<u64>, ParseError>>()?;
+
+        let mut maps: Vec<AgMap> = vec![];
+        for s in rest.split("\n\n").filter(|&s| !s.is_empty()) {
+            let map = s.parse::<AgMap>().map_err(|e| e.in_context(input, s))?;
+            maps.push(map);
+        }
+
+        return Ok(Almanac { seeds, maps });
+    }
+
+    fn part_1(almanac: &Self::Input) -> Result<u64, String> {
+        if almanac.seeds.is_empty() {
+            return Err("there are no seeds".to_owned());
+        }
+
+        let mut min = u64::MAX;
+        for seed in almanac.seeds.iter() {
+            let mut mapping = *seed;
+            for map in &almanac.maps {
+                mapping = map.map_value(mapping);
+            }
+
+            if mapping < min {
+                min = mapping;
+            }
+        }
+
+        Ok(min)
+    }
+
+    fn part_2(almanac: &Self::Input) -> Result<u64, String> {
+        if almanac.seeds.is_empty() {
+            return Err("there are no seeds".to_owned());
+        }
+        if almanac.seeds.len() % 2 != 0 {
+            return Err("the seeds don't pair up into starts and lengths".to_owned());
+        }
+        let seed_ranges: Vec<Range<u64>> = almanac
+            .seeds
+            .chunks(2)
+            .map(|chunk| match chunk[0].checked_add(chunk[1]) {
+                Some(end) => Ok(chunk[0]..end),
+                None => Err(format!("the seeds {} {} end past 2^64", chunk[0], chunk[1])),
+            })
+            .collect::<Result<Vec<Range<u64>>, String>>()?;
+
+        let map = seed_ranges.par_iter().map(|range| {
+            let mut ranges_to_map: Vec<Range<u64>> = vec![range.clone()];
+
+            for map in almanac.maps.iter() {
+                let mut mapped_ranges: Vec<Range<u64>> = vec![];
+                for range in ranges_to_map.iter() {
+                    mapped_ranges.extend(map.map_range(range));
+                }
+                ranges_to_map = mapped_ranges;
+            }
+
+            return ranges_to_map;
+        });
+
+        let min = map.reduce(
+            Vec::<Range<u64>>::new,
+            |a: Vec<Range<u64>>, b: Vec<Range<u64>>| {
+                let max = u64::MAX..u64::MAX;
+                let min_a = match a.iter().min_by(|&a, &b| a.start.cmp(&b.start)) {
+                    Some(v) => v,
+                    None => &max,
+                };
+                let min_b = match b.iter().min_by(|&a, &b| a.start.cmp(&b.start)) {
+                    Some(v) => v,
+                    None => &max,
+                };
+                if min_a.start < min_b.start {
+                    return vec![min_a.clone()];
+                }
+                return vec![min_b.clone()];
+            },
+        );
+
+        Ok(min[0].start)
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn recorded_answers() {
+    aoc_core::testing::check_answers::<day_5::Day5>();
+}
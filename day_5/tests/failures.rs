@@ -0,0 +1,22 @@
+use aoc_core::Solution;
+use day_5::Day5;
+
+#[test]
+fn seeds_not_in_pairs_have_no_ranges() {
+    let almanac = Day5::parse("seeds: 79\n\nseed-to-soil map:\n50 98 2\n").unwrap();
+    assert_eq!(Day5::part_1(&almanac), Ok(79));
+    assert_eq!(
+        Day5::part_2(&almanac),
+        Err("the seeds don't pair up into starts and lengths".to_owned())
+    );
+}
+
+#[test]
+fn rejects_ranges_ending_past_64_bits() {
+    let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 18446744073709551615\n";
+    let Err(error) = Day5::parse(input) else {
+        panic!("an overflowing range parsed");
+    };
+    assert_eq!((error.line, error.column), (4, 7));
+    assert_eq!(error.expected, "a range length ending below 2^64");
+}
@@ -0,0 +1,182 @@
+use aoc_core::{ParseError, Solution};
+use std::ops::Range;
+
+#[derive(Debug)]
+pub struct Race {
+    time: u32,
+    record_distance: u128,
+}
+
+impl Race {
+    fn build(time: u32, record_distance: u128) -> Self {
+        Self {
+            time,
+            record_distance,
+        }
+    }
+}
+
+fn has_won_race(race: &Race, time: u32) -> bool {
+    u128::from(time) * u128::from(race.time - time) > race.record_distance
+}
+
+fn add_unsigned_negative(val: u32, direction: i32) -> u32 {
+    if direction < 0 {
+        if val == 0 {
+            return 0;
+        }
+        return val - -direction as u32;
+    }
+
+    val + direction as u32
+}
+
+fn scan_for_range_bound(race: &Race, time: u32, direction: i32) -> u32 {
+    let mut curr = add_unsigned_negative(time, direction);
+    while curr > 0 || curr <= race.time {
+        if !has_won_race(race, curr) {
+            break;
+        }
+
+        curr = add_unsigned_negative(curr, direction);
+    }
+
+    return curr;
+}
+
+fn find_winning_value(race: &Race, low: u32, high: u32) -> Option<u32> {
+    let mid = (low as f64 + (high as f64 - low as f64) / 2.0) as u32;
+    if has_won_race(race, mid) {
+        return Some(mid);
+    }
+    if low >= high {
+        return None;
+    }
+
+    let maybe_left = find_winning_value(race, low, mid);
+    let maybe_right = find_winning_value(race, mid + 1, high);
+
+    if maybe_left.is_some() {
+        return maybe_left;
+    }
+    if maybe_right.is_some() {
+        return maybe_right;
+    }
+
+    return None;
+}
+
+fn find_winning_range(race: &Race) -> Option<Range<u32>> {
+    let winning_value = find_winning_value(race, 0, race.time)?;
+    let first_bound = scan_for_range_bound(race, winning_value, -1) + 1;
+    let second_bound = scan_for_range_bound(race, winning_value, 1);
+
+    return Some(first_bound..second_bound);
+}
+
+fn range_difference(range: &Range<u32>) -> u32 {
+    let diff = range.end - range.start;
+
+    if diff == 0 {
+        return 1;
+    }
+
+    diff
+}
+
+fn parse_values<'a>(line: &'a str, label: &str) -> Result<Vec<&'a str>, ParseError> {
+    let Some(rest) = line.strip_prefix(label) else {
+        let word = line.split(' ').next().unwrap();
+        return Err(ParseError::new(line, word, &format!("`{}`", label)));
+    };
+
+    Ok(rest.split(' ').filter(|s| !s.is_empty()).collect())
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Race>;
+    type Answer1 = Result<u64, String>;
+    type Answer2 = Result<u32, String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let Some((line1, line2)) = input.split_once("\n") else {
+            return Err(ParseError::at_end(input, "a `Distance:` line"));
+        };
+
+        let line2 = line2.trim_end_matches('\n');
+        let line1_vals = parse_values(line1, "Time:").map_err(|e| e.in_context(input, line1))?;
+        let line2_vals =
+            parse_values(line2, "Distance:").map_err(|e| e.in_context(input, line2))?;
+
+        if line2_vals.len() != line1_vals.len() {
+            let expected = format!("{} distances, one per race", line1_vals.len());
+            return Err(ParseError::new(input, line2, &expected));
+        }
+
+        let mut races: Vec<Race> = vec![];
+        for (i, str) in line1_vals.iter().enumerate() {
+            let time = str
+                .parse::<u32>()
+                .map_err(|_| ParseError::new(input, str, "a race time"))?;
+            let distance = line2_vals[i]
+                .parse::<u128>()
+                .map_err(|_| ParseError::new(input, line2_vals[i], "a record distance"))?;
+
+            races.push(Race::build(time, distance));
+        }
+
+        Ok(races)
+    }
+
+    fn part_1(races: &Self::Input) -> Result<u64, String> {
+        let mut winning_ranges: Vec<Range<u32>> = vec![];
+        for race in races.iter() {
+            let Some(winning_range) = find_winning_range(race) else {
+                continue;
+            };
+            winning_ranges.push(winning_range);
+        }
+
+        winning_ranges
+            .iter()
+            .try_fold(1u64, |acc, range| {
+                acc.checked_mul(range_difference(range) as u64)
+            })
+            .ok_or_else(|| "the product of the ways to win doesn't fit in 64 bits".to_owned())
+    }
+
+    fn part_2(races: &Self::Input) -> Result<u32, String> {
+        let (long_time, long_record) =
+            races.iter().fold(("".to_owned(), "".to_owned()), |acc, r| {
+                (
+                    format!("{}{}", acc.0, r.time),
+                    format!("{}{}", acc.1, r.record_distance),
+                )
+            });
+        if races.is_empty() {
+            return Err("there are no races".to_owned());
+        }
+        let time = long_time
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| format!("the time {} doesn't fit in 32 bits", long_time))?;
+        let record_distance = long_record
+            .trim()
+            .parse::<u128>()
+            .map_err(|_| format!("the distance {} doesn't fit in 128 bits", long_record))?;
+        let race = Race::build(time, record_distance);
+        let Some(winning_range) = find_winning_range(&race) else {
+            return Err(format!(
+                "no one can beat {} mm in {} ms",
+                record_distance, time
+            ));
+        };
+
+        Ok(range_difference(&winning_range))
+    }
+}
@@ -0,0 +1,21 @@
+use aoc_core::Solution;
+use day_6::Day6;
+
+#[test]
+fn times_too_long_for_32_bits_fail() {
+    let races = Day6::parse("Time: 60000 70000\nDistance: 1 1\n").unwrap();
+    assert_eq!(Day6::part_1(&races), Ok(59999 * 69999));
+    assert_eq!(
+        Day6::part_2(&races),
+        Err("the time 6000070000 doesn't fit in 32 bits".to_owned())
+    );
+}
+
+#[test]
+fn races_no_one_can_win_fail() {
+    let races = Day6::parse("Time: 7\nDistance: 100\n").unwrap();
+    assert_eq!(
+        Day6::part_2(&races),
+        Err("no one can beat 100 mm in 7 ms".to_owned())
+    );
+}
@@ -0,0 +1,223 @@
+use aoc_core::{ParseError, Solution};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+enum Card {
+    J = 1,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    T,
+    Q,
+    K,
+    A,
+}
+
+impl Card {
+    fn from_char(c: &char) -> Option<Self> {
+        let card = match c {
+            '2' => Card::Two,
+            '3' => Card::Three,
+            '4' => Card::Four,
+            '5' => Card::Five,
+            '6' => Card::Six,
+            '7' => Card::Seven,
+            '8' => Card::Eight,
+            '9' => Card::Nine,
+            'T' => Card::T,
+            'J' => Card::J,
+            'Q' => Card::Q,
+            'K' => Card::K,
+            'A' => Card::A,
+            _ => return None,
+        };
+        Some(card)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+enum HandType {
+    HighCard = 1,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    fn build(hand_counts: &HashMap<Card, usize>) -> Self {
+        if hand_counts.len() == 5 {
+            return Self::HighCard;
+        } else if hand_counts.len() == 4 {
+            return Self::OnePair;
+        } else if hand_counts.len() == 1 {
+            return Self::FiveOfAKind;
+        } else if hand_counts.len() == 3 {
+            if hand_counts.values().any(|v| *v == 3) {
+                return Self::ThreeOfAKind;
+            }
+            return Self::TwoPair;
+        } else if hand_counts.values().any(|v| *v == 4) {
+            return Self::FourOfAKind;
+        }
+
+        Self::FullHouse
+    }
+
+    fn build_part_2(hand: &Hand) -> Self {
+        let joker_count: usize = match hand.hand_counts.get(&Card::J) {
+            Some(count) => *count,
+            None => 0,
+        };
+
+        if joker_count == 0 || hand.hand_type == Self::FiveOfAKind {
+            return hand.hand_type;
+        }
+
+        if hand.hand_type == Self::FourOfAKind || hand.hand_type == Self::FullHouse {
+            return Self::FiveOfAKind;
+        } else if hand.hand_type == Self::ThreeOfAKind {
+            return Self::FourOfAKind;
+        } else if hand.hand_type == Self::HighCard {
+            return Self::OnePair;
+        } else if hand.hand_type == Self::OnePair {
+            return Self::ThreeOfAKind;
+        }
+
+        // Hand type is TwoPair
+        if joker_count == 1 {
+            return Self::FullHouse;
+        }
+        return Self::FourOfAKind;
+    }
+}
+
+#[derive(Debug, Clone, Eq)]
+pub struct Hand {
+    hand: Vec<Card>,
+    hand_type: HandType,
+    hand_counts: HashMap<Card, usize>,
+    score: u32,
+}
+
+impl Hand {
+    fn build(hand: Vec<Card>, score: u32) -> Self {
+        let hand_counts = hand.iter().fold(HashMap::new(), |mut acc, &card| {
+            match acc.get(&card) {
+                Some(v) => acc.insert(card, v + 1),
+                None => acc.insert(card, 1),
+            };
+            acc
+        });
+
+        Hand {
+            hand,
+            hand_type: HandType::build(&hand_counts),
+            hand_counts,
+            score,
+        }
+    }
+
+    fn recompute_hand_type_part2(&mut self) {
+        self.hand_type = HandType::build_part_2(self);
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((hand_str, score_str)) = s.split_once(" ") else {
+            return Err(ParseError::at_end(s, "a bid"));
+        };
+
+        let mut hand: Vec<Card> = vec![];
+        for (i, c) in hand_str.char_indices() {
+            let Some(card) = Card::from_char(&c) else {
+                let found = &hand_str[i..i + c.len_utf8()];
+                return Err(ParseError::new(s, found, "a card label"));
+            };
+            hand.push(card);
+        }
+        if hand.len() != 5 {
+            return Err(ParseError::new(s, hand_str, "a hand of 5 cards"));
+        }
+
+        let score = score_str
+            .parse::<u32>()
+            .map_err(|_| ParseError::new(s, score_str, "a bid"))?;
+
+        Ok(Hand::build(hand, score))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.hand_type.cmp(&other.hand_type) {
+            Ordering::Equal => self.hand.cmp(&other.hand),
+            Ordering::Less => Ordering::Less,
+            Ordering::Greater => Ordering::Greater,
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.hand_type == other.hand_type && self.hand == other.hand
+    }
+}
+
+fn total_winnings(hands: &[Hand]) -> u32 {
+    hands
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, hand)| acc + hand.score * (i + 1) as u32)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Hand>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc_core::parse_lines(input)
+    }
+
+    fn part_1(hands: &Self::Input) -> u32 {
+        let mut hands = hands.clone();
+        hands.sort_unstable();
+
+        total_winnings(&hands)
+    }
+
+    fn part_2(hands: &Self::Input) -> u32 {
+        let mut hands = hands.clone();
+        for hand in hands.iter_mut() {
+            hand.recompute_hand_type_part2();
+        }
+
+        hands.sort_unstable();
+
+        total_winnings(&hands)
+    }
+}
@@ -0,0 +1,150 @@
+use aoc_core::{ParseError, Solution};
+use std::collections::HashMap;
+use num::Integer;
+use std::str::FromStr;
+
+// Steps from the start to an end location, or why there is no such path
+fn follow_directions(
+    directions: &str,
+    map: &HashMap<String, (String, String)>,
+    start_location: &str,
+    is_end_location: fn(&str) -> bool,
+) -> Result<u32, String> {
+    let mut count = 0;
+    let mut location = start_location;
+    let mut dir_iter = directions.chars().cycle();
+    // Past this many steps some (location, direction) pair has repeated, so the
+    // walk loops without reaching an end
+    let limit = directions.len() * map.len().max(1);
+
+    while !is_end_location(location) {
+        let Some((left, right)) = map.get(location) else {
+            return Err(format!("the map has no node {}", location));
+        };
+        if count as usize > limit {
+            return Err(format!(
+                "the map loops from {} without reaching an end",
+                start_location
+            ));
+        }
+
+        location = match dir_iter.next() {
+            Some('L') => left,
+            _ => right,
+        };
+
+        count += 1
+    }
+
+    Ok(count)
+}
+
+fn last_char(s: &str) -> char {
+    s.chars().last().unwrap()
+}
+
+fn lcm_directions(
+    directions: &str,
+    map: &HashMap<String, (String, String)>,
+) -> Result<u64, String> {
+    let locations: Vec<&str> = map
+        .keys()
+        .filter(|k| last_char(k) == 'A')
+        .map(|k| k.as_str())
+        .collect();
+    let end_distances: Vec<u32> = locations
+        .iter()
+        .map(|l| follow_directions(directions, map, l, |v| last_char(v) == 'Z'))
+        .collect::<Result<_, _>>()?;
+    Ok(end_distances
+        .into_iter()
+        .fold(1, |acc, dist| acc.lcm(&(dist as u64))))
+}
+
+pub struct Network {
+    directions: String,
+    map: HashMap<String, (String, String)>,
+}
+
+// A single `AAA = (BBB, CCC)` line of the network
+struct Node {
+    key: String,
+    left: String,
+    right: String,
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((key, vals)) = s.split_once(" = ") else {
+            return Err(ParseError::at_end(s, "` = `"));
+        };
+        let Some(inner) = vals.strip_prefix('(') else {
+            return Err(ParseError::new(s, vals, "`(`"));
+        };
+        let Some(inner) = inner.strip_suffix(')') else {
+            return Err(ParseError::at_end(s, "`)`"));
+        };
+        let Some((left, right)) = inner.split_once(", ") else {
+            return Err(ParseError::new(s, inner, "`<left>, <right>`"));
+        };
+        if let Some(&name) = [key, left, right].iter().find(|name| name.is_empty()) {
+            return Err(ParseError::new(s, name, "a node name"));
+        }
+
+        Ok(Node {
+            key: key.to_owned(),
+            left: left.to_owned(),
+            right: right.to_owned(),
+        })
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Network;
+    type Answer1 = Result<u32, String>;
+    type Answer2 = Result<u64, String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let Some((directions, maps_str)) = input.split_once("\n\n") else {
+            return Err(ParseError::at_end(
+                input,
+                "a blank line after the directions",
+            ));
+        };
+        if directions.is_empty() {
+            return Err(ParseError::new(input, directions, "`L` or `R`"));
+        }
+        if let Some(i) = directions.find(|c| c != 'L' && c != 'R') {
+            return Err(ParseError::new(input, &directions[i..i + 1], "`L` or `R`"));
+        }
+
+        let nodes: Vec<Node> =
+            aoc_core::parse_lines(maps_str).map_err(|e| e.in_context(input, maps_str))?;
+        let map: HashMap<String, (String, String)> = HashMap::new();
+        let mut map = nodes.into_iter().fold(map, |mut acc, node| {
+            acc.insert(node.key, (node.left, node.right));
+            acc
+        });
+        map.shrink_to_fit();
+
+        Ok(Network {
+            directions: directions.to_owned(),
+            map,
+        })
+    }
+
+    fn part_1(network: &Self::Input) -> Result<u32, String> {
+        follow_directions(&network.directions, &network.map, "AAA", |l| l == "ZZZ")
+    }
+
+    fn part_2(network: &Self::Input) -> Result<u64, String> {
+        lcm_directions(&network.directions, &network.map)
+    }
+}
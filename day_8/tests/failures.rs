@@ -0,0 +1,53 @@
+use aoc_core::runner::{self, Part};
+use aoc_core::Solution;
+use day_8::Day8;
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn a_part_without_an_answer_does_not_stop_the_other() {
+    let text = fs::read_to_string(Path::new(Day8::DIR).join("part_2_sample_input.txt")).unwrap();
+    let report = runner::execute::<Day8>(&text, Part::BOTH).unwrap();
+
+    assert_eq!(
+        report.answer(Part::One),
+        Some(Err("the map has no node AAA"))
+    );
+    assert_eq!(report.answer(Part::Two), Some(Ok("6")));
+
+    // Parts that can fail report the type of their answer all the same
+    let types: Vec<&str> = report.outcomes.iter().map(|o| o.answer_type).collect();
+    assert_eq!(types, ["u32", "u64"]);
+}
+
+#[test]
+fn walks_that_never_end_fail() {
+    let network = Day8::parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\n").unwrap();
+    assert_eq!(
+        Day8::part_1(&network),
+        Err("the map loops from AAA without reaching an end".to_owned())
+    );
+}
+
+#[test]
+fn rejects_empty_directions() {
+    let Err(error) = Day8::parse("\n\nAAA = (AAA, AAA)\n") else {
+        panic!("empty directions parsed");
+    };
+    assert_eq!((error.line, error.expected.as_str()), (1, "`L` or `R`"));
+}
+
+#[test]
+fn rejects_empty_node_names() {
+    for (input, column) in [
+        ("L\n\n = (AAA, AAA)\n", 1),
+        ("L\n\nAAA = (, AAA)\n", 8),
+        ("L\n\nAAA = (AAA, )\n", 13),
+    ] {
+        let Err(error) = Day8::parse(input) else {
+            panic!("an empty name parsed in {:?}", input);
+        };
+        assert_eq!((error.line, error.column), (3, column), "{:?}", input);
+        assert_eq!(error.expected, "a node name");
+    }
+}
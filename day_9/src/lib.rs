@@ -0,0 +1,59 @@
+use aoc_core::{ParseError, Solution};
+use itertools::Itertools;
+
+fn parse_sequence(line: &str) -> Result<Vec<i32>, ParseError> {
+    line.split_whitespace()
+        .map(|s| s.parse().map_err(|_| ParseError::new(line, s, "a number")))
+        .collect()
+}
+
+fn find_next_val(seq: &[i32], acc: i32) -> i32 {
+    if seq.iter().all(|&v| v == 0) {
+        return acc;
+    }
+
+    let diffs: Vec<i32> = seq.iter().tuple_windows().map(|(l, r)| r - l).collect();
+    return find_next_val(&diffs, seq.iter().last().unwrap() + acc);
+}
+
+fn find_prev_val(seq: &[i32]) -> i32 {
+    if seq.iter().all(|&v| v == 0) {
+        return 0;
+    }
+
+    let diffs: Vec<i32> = seq.iter().tuple_windows().map(|(l, r)| r - l).collect();
+    return seq.first().unwrap() - find_prev_val(&diffs);
+}
+
+fn part_1(seq: &[Vec<i32>]) -> i32 {
+    seq.iter().map(|s| find_next_val(s, 0)).sum()
+}
+
+fn part_2(seq: &[Vec<i32>]) -> i32 {
+    seq.iter().map(|s| find_prev_val(s)).sum()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc_core::lines(input)
+            .map(|line| parse_sequence(line).map_err(|e| e.in_context(input, line)))
+            .collect()
+    }
+
+    fn part_1(sequences: &Self::Input) -> i32 {
+        part_1(sequences)
+    }
+
+    fn part_2(sequences: &Self::Input) -> i32 {
+        part_2(sequences)
+    }
+}